@@ -0,0 +1,132 @@
+//! Benchmarks laying out and opening a [`PickList`] with a large amount of
+//! options, with and without virtualization.
+//!
+//! Run them with `cargo bench --bench pick_list`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::Tree;
+use iced::advanced::{clipboard, mouse, renderer, Shell};
+use iced::widget::text;
+use iced::{Event, Font, Point, Rectangle, Size, Theme, Vector};
+
+use sweeten::widget::pick_list::{PickList, WidthHint};
+
+type Renderer = iced_tiny_skia::Renderer;
+type Element<'a> = iced::Element<'a, usize, Theme, Renderer>;
+
+const OPTIONS: usize = 50_000;
+
+fn options() -> Vec<String> {
+    (0..OPTIONS).map(|i| format!("sku-{i:05}")).collect()
+}
+
+fn pick_list(
+    options: &[String],
+) -> PickList<'_, String, &[String], String, usize, Theme, Renderer> {
+    PickList::new(options, None, |_| 0)
+}
+
+fn layout(c: &mut Criterion) {
+    let renderer = Renderer::new(Font::DEFAULT, 16.into());
+    let limits = layout::Limits::new(Size::ZERO, Size::new(800.0, 600.0));
+    let options = options();
+
+    let mut bench = |name: &str, element: Element<'_>| {
+        let mut tree = Tree::new(&element);
+
+        let _ = c.bench_function(name, |b| {
+            b.iter(|| {
+                element.as_widget().layout(
+                    &mut tree,
+                    black_box(&renderer),
+                    &limits,
+                )
+            })
+        });
+    };
+
+    bench("layout", pick_list(&options).into());
+    bench(
+        "layout sampled",
+        pick_list(&options)
+            .virtualized(WidthHint::Sample(100))
+            .into(),
+    );
+    bench(
+        "layout fixed",
+        pick_list(&options)
+            .virtualized(WidthHint::Fixed(200.0))
+            .into(),
+    );
+}
+
+fn open(c: &mut Criterion) {
+    let mut renderer = Renderer::new(Font::DEFAULT, 16.into());
+    let limits = layout::Limits::new(Size::ZERO, Size::new(800.0, 600.0));
+    let viewport = Rectangle::with_size(Size::new(800.0, 600.0));
+    let options = options();
+
+    let mut bench = |name: &str, mut element: Element<'_>| {
+        let mut tree = Tree::new(&element);
+        let node = element.as_widget().layout(&mut tree, &renderer, &limits);
+        let cursor = mouse::Cursor::Available(Point::new(5.0, 5.0));
+
+        let _ = c.bench_function(name, |b| {
+            b.iter(|| {
+                let mut tree = Tree::new(&element);
+                let mut messages = Vec::new();
+
+                let _ = element.as_widget_mut().on_event(
+                    &mut tree,
+                    Event::Mouse(mouse::Event::ButtonPressed(
+                        mouse::Button::Left,
+                    )),
+                    Layout::new(&node),
+                    cursor,
+                    &renderer,
+                    &mut clipboard::Null,
+                    &mut Shell::new(&mut messages),
+                    &viewport,
+                );
+
+                let mut overlay = element
+                    .as_widget_mut()
+                    .overlay(
+                        &mut tree,
+                        Layout::new(&node),
+                        &renderer,
+                        Vector::ZERO,
+                    )
+                    .expect("Menu should be open");
+
+                let menu = overlay.layout(&renderer, viewport.size());
+
+                overlay.draw(
+                    &mut renderer,
+                    &Theme::Light,
+                    &renderer::Style::default(),
+                    Layout::new(&menu),
+                    cursor,
+                );
+            })
+        });
+    };
+
+    bench(
+        "open with views",
+        pick_list(&options)
+            .option_view(|option| text(option.clone()).into())
+            .into(),
+    );
+    bench(
+        "open with views virtualized",
+        pick_list(&options)
+            .option_view(|option| text(option.clone()).into())
+            .virtualized(WidthHint::Sample(100))
+            .into(),
+    );
+}
+
+criterion_group!(benches, layout, open);
+criterion_main!(benches);
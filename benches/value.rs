@@ -0,0 +1,70 @@
+//! Benchmarks the editing operations of a [`Value`] on large contents.
+//!
+//! Run them with `cargo bench --bench value`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use sweeten::widget::text_input::Value;
+
+/// Roughly 100 KB of mixed ASCII, accented and emoji text.
+fn contents() -> String {
+    "The quick brown fox jumps over the lazy dog. Café naïve 👩‍💻 🇺🇸\n"
+        .repeat(1_400)
+}
+
+fn value(c: &mut Criterion) {
+    let contents = contents();
+    let value = Value::new(&contents);
+    let middle = value.len() / 2;
+
+    let _ = c
+        .bench_function("new", |b| b.iter(|| Value::new(black_box(&contents))));
+
+    let _ = c.bench_function("insert at end", |b| {
+        b.iter_batched_ref(
+            || value.clone(),
+            |value| value.insert(value.len(), 'a'),
+            criterion::BatchSize::LargeInput,
+        )
+    });
+
+    let _ = c.bench_function("insert in middle", |b| {
+        b.iter_batched_ref(
+            || value.clone(),
+            |value| value.insert(middle, 'a'),
+            criterion::BatchSize::LargeInput,
+        )
+    });
+
+    let _ = c.bench_function("insert many in middle", |b| {
+        b.iter_batched_ref(
+            || (value.clone(), value.select(0, 100)),
+            |(value, pasted)| value.insert_many(middle, pasted.clone()),
+            criterion::BatchSize::LargeInput,
+        )
+    });
+
+    let _ = c.bench_function("remove in middle", |b| {
+        b.iter_batched_ref(
+            || value.clone(),
+            |value| value.remove(middle),
+            criterion::BatchSize::LargeInput,
+        )
+    });
+
+    let _ = c.bench_function("previous start of word", |b| {
+        b.iter(|| value.previous_start_of_word(black_box(middle)))
+    });
+
+    let _ = c.bench_function("next end of word", |b| {
+        b.iter(|| value.next_end_of_word(black_box(middle)))
+    });
+
+    let _ = c.bench_function("until middle", |b| {
+        b.iter(|| value.until(black_box(middle)))
+    });
+
+    let _ = c.bench_function("to string", |b| b.iter(|| value.to_string()));
+}
+
+criterion_group!(benches, value);
+criterion_main!(benches);
@@ -0,0 +1,100 @@
+use iced::widget::{center, column, text};
+use iced::{Alignment::Center, Element, Fill};
+
+use sweeten::widget::multi_pick_list;
+
+fn main() -> iced::Result {
+    iced::application(
+        "sweetened iced - MultiPickList example",
+        App::update,
+        App::view,
+    )
+    .window_size((300.0, 300.0))
+    .theme(App::theme)
+    .run()
+}
+
+#[derive(Default)]
+struct App {
+    selected_languages: Vec<Language>,
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Change(Vec<Language>),
+}
+
+impl App {
+    fn theme(&self) -> iced::Theme {
+        iced::Theme::TokyoNightLight
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Change(languages) => {
+                self.selected_languages = languages;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let multi_pick_list =
+            multi_pick_list(&Language::ALL[..], &self.selected_languages[..])
+                .disabled_if(|language| {
+                    matches!(language, Language::Javascript)
+                        .then_some("Nobody speaks it")
+                })
+                .on_change(Message::Change)
+                .placeholder("Choose some languages...")
+                .width(200);
+
+        center(
+            column![
+                "Which languages do you speak?",
+                multi_pick_list,
+                text!("{} selected", self.selected_languages.len()),
+            ]
+            .width(Fill)
+            .align_x(Center)
+            .spacing(10),
+        )
+        .into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    Elm,
+    Ruby,
+    Haskell,
+    C,
+    Javascript,
+    Other,
+}
+
+impl Language {
+    const ALL: [Language; 7] = [
+        Language::C,
+        Language::Javascript,
+        Language::Elm,
+        Language::Ruby,
+        Language::Haskell,
+        Language::Rust,
+        Language::Other,
+    ];
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Language::Rust => "Rust",
+            Language::Elm => "Elm",
+            Language::Ruby => "Ruby",
+            Language::Haskell => "Haskell",
+            Language::C => "C",
+            Language::Javascript => "Javascript",
+            Language::Other => "Some other language",
+        })
+    }
+}
@@ -22,6 +22,7 @@ struct App {
 #[derive(Clone, Debug)]
 enum Message {
     Pick(Language),
+    Clear,
 }
 
 impl App {
@@ -34,22 +35,24 @@ impl App {
             Message::Pick(option) => {
                 self.selected_language = Some(option);
             }
+            Message::Clear => {
+                self.selected_language = None;
+            }
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let pick_list = pick_list(
             &Language::ALL[..],
-            Some(|languages: &[Language]| {
-                languages
-                    .iter()
-                    .map(|lang| matches!(lang, Language::Javascript))
-                    .collect()
-            }),
             self.selected_language,
             Message::Pick,
         )
-        .placeholder("Choose a language...");
+        .disabled_if(|language| {
+            matches!(language, Language::Javascript).then_some("Nice try")
+        })
+        .placeholder("Choose a language...")
+        .on_clear(Message::Clear)
+        .searchable(true);
 
         center(
             column![
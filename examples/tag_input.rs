@@ -0,0 +1,66 @@
+use iced::widget::{center, column};
+use iced::{Element, Fill};
+
+use sweeten::widget::tag_input;
+
+fn main() -> iced::Result {
+    iced::application(
+        "sweetened iced - TagInput example",
+        App::update,
+        App::view,
+    )
+    .window_size((400.0, 200.0))
+    .theme(App::theme)
+    .run()
+}
+
+#[derive(Default)]
+struct App {
+    tags: Vec<String>,
+    draft: String,
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    DraftChanged(String),
+    TagAdded(String),
+    TagRemoved(usize),
+}
+
+impl App {
+    fn theme(&self) -> iced::Theme {
+        iced::Theme::TokyoNightLight
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::DraftChanged(draft) => {
+                self.draft = draft;
+            }
+            Message::TagAdded(tag) => {
+                if !self.tags.contains(&tag) {
+                    self.tags.push(tag);
+                }
+                self.draft.clear();
+            }
+            Message::TagRemoved(index) => {
+                self.tags.remove(index);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tag_input = tag_input("Add a tag...", &self.draft, &self.tags)
+            .on_input(Message::DraftChanged)
+            .on_add(Message::TagAdded)
+            .on_remove(Message::TagRemoved)
+            .width(Fill);
+
+        center(
+            column!["Type and press Enter or comma to add a tag:", tag_input]
+                .spacing(10),
+        )
+        .padding(20)
+        .into()
+    }
+}
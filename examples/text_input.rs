@@ -2,7 +2,7 @@ use iced::advanced::widget;
 use iced::widget::{button, center, column, container, row, text};
 use iced::{Center, Element, Fill, Subscription, Task};
 
-use sweeten::widget::text_input;
+use sweeten::widget::{focus_scope, text_input};
 
 fn main() -> iced::Result {
     iced::application(
@@ -189,19 +189,17 @@ impl App {
                 }
             }
             Message::FocusNext => {
-                return text_input::focus_next(|id| {
-                    Field::from_widget_id(id).map_or_else(
-                        || Task::none(),
-                        |field| Task::done(Message::InputFocused(field)),
-                    )
+                return focus_scope::focus_next_in("form", |id| {
+                    Field::from_widget_id(id).map_or_else(Task::none, |field| {
+                        Task::done(Message::InputFocused(field))
+                    })
                 });
             }
             Message::FocusPrevious => {
-                return text_input::focus_previous(|id| {
-                    Field::from_widget_id(id).map_or_else(
-                        || Task::none(),
-                        |field| Task::done(Message::InputFocused(field)),
-                    )
+                return focus_scope::focus_previous_in("form", |id| {
+                    Field::from_widget_id(id).map_or_else(Task::none, |field| {
+                        Task::done(Message::InputFocused(field))
+                    })
                 });
             }
         }
@@ -224,7 +222,7 @@ impl App {
             let input_widget = text_input(field.placeholder(), value)
                 .id(field.id())
                 .on_input(move |text| Message::InputChanged(field, text))
-                .on_focus(move |_| Message::InputFocused(field))
+                .on_focus(move |_, _| Message::InputFocused(field))
                 .on_blur(Message::InputBlurred(field))
                 .width(Fill)
                 .secure(field == Field::Password);
@@ -254,7 +252,7 @@ impl App {
         };
 
         let submit_button = button(text("Submit").center())
-            .on_press_maybe(self.form_is_valid().then(|| Message::SubmitForm))
+            .on_press_maybe(self.form_is_valid().then_some(Message::SubmitForm))
             .width(120);
 
         let form_status_content = if self.username.error().is_some()
@@ -275,7 +273,8 @@ impl App {
             });
 
         Element::from(
-            center(
+            center(focus_scope(
+                "form",
                 column![
                     create_field_view(&self.username),
                     create_field_view(&self.password),
@@ -289,7 +288,7 @@ impl App {
                 .width(400)
                 .align_x(Center)
                 .spacing(20),
-            )
+            ))
             .padding(20),
         )
     }
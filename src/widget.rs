@@ -2,11 +2,27 @@ use iced::advanced::text;
 use iced::Element;
 use std::borrow::Borrow;
 
+pub mod focus_scope;
 pub mod mouse_area;
+pub mod multi_pick_list;
+pub mod operation;
 pub mod overlay;
 pub mod pick_list;
+pub mod tag_input;
 pub mod text_input;
 
+/// A container keeping [`focus_scope::focus_next_in`] and
+/// [`focus_scope::focus_previous_in`] within its content.
+pub fn focus_scope<'a, Message, Theme, Renderer>(
+    id: impl Into<focus_scope::Id>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> focus_scope::FocusScope<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::renderer::Renderer,
+{
+    focus_scope::FocusScope::new(id, content)
+}
+
 /// A container intercepting mouse events.
 pub fn mouse_area<'a, Message, Theme, Renderer>(
     widget: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -17,11 +33,27 @@ where
     mouse_area::MouseArea::new(widget)
 }
 
+/// Multi pick lists display a dropdown list of options that can be toggled,
+/// some of which may be disabled.
+pub fn multi_pick_list<'a, T, L, V, Message, Theme, Renderer>(
+    options: L,
+    selected: V,
+) -> multi_pick_list::MultiPickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<[T]> + 'a,
+    Message: Clone,
+    Theme: pick_list::Catalog + overlay::menu::Catalog,
+    Renderer: text::Renderer,
+{
+    multi_pick_list::MultiPickList::new(options, selected)
+}
+
 /// Pick lists display a dropdown list of selectable options, some of which
 /// may be disabled.
 pub fn pick_list<'a, T, L, V, Message, Theme, Renderer>(
     options: L,
-    disabled: Option<impl Fn(&[T]) -> Vec<bool> + 'a>,
     selected: Option<V>,
     on_selected: impl Fn(T) -> Message + 'a,
 ) -> pick_list::PickList<'a, T, L, V, Message, Theme, Renderer>
@@ -33,7 +65,29 @@ where
     Theme: pick_list::Catalog + overlay::menu::Catalog,
     Renderer: text::Renderer,
 {
-    pick_list::PickList::new(options, disabled, selected, on_selected)
+    pick_list::PickList::new(options, selected, on_selected)
+}
+
+/// Creates a new [`TagInput`].
+///
+/// Tag inputs turn committed text into a row of removable tags, followed by
+/// an editable [`TextInput`].
+///
+/// [`TagInput`]: tag_input::TagInput
+/// [`TextInput`]: text_input::TextInput
+pub fn tag_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    value: &str,
+    tags: impl IntoIterator<Item = impl ToString>,
+) -> tag_input::TagInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: tag_input::Catalog + 'a,
+    Renderer: text::Renderer,
+    <Theme as text_input::Catalog>::Class<'a>:
+        From<text_input::StyleFn<'a, Theme>>,
+{
+    tag_input::TagInput::new(placeholder, value, tags)
 }
 
 /// Creates a new [`TextInput`].
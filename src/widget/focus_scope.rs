@@ -0,0 +1,264 @@
+//! Keep keyboard focus inside a part of the widget tree.
+use iced::advanced::widget::Operation;
+use iced::advanced::widget::{self, Tree, Widget};
+use iced::advanced::{
+    layout, mouse, overlay, renderer, Clipboard, Layout, Shell,
+};
+use iced::event::{self, Event};
+use iced::{Element, Length, Rectangle, Size, Task, Vector};
+
+use crate::widget::operation;
+
+/// A container that groups focusable widgets, so that [`focus_next_in`] and
+/// [`focus_previous_in`] can cycle through them without leaving the scope.
+#[allow(missing_debug_implementations)]
+pub struct FocusScope<
+    'a,
+    Message,
+    Theme = iced::Theme,
+    Renderer = iced::Renderer,
+> {
+    id: Id,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> FocusScope<'a, Message, Theme, Renderer> {
+    /// Creates a [`FocusScope`] with the given [`Id`] and content.
+    pub fn new(
+        id: impl Into<Id>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        FocusScope {
+            id: id.into(),
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FocusScope<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(
+            Some(&self.id.0),
+            layout.bounds(),
+            &mut |operation| {
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            },
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<FocusScope<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        scope: FocusScope<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(scope)
+    }
+}
+
+/// The identifier of a [`FocusScope`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+impl From<&'static str> for Id {
+    fn from(id: &'static str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<String> for Id {
+    fn from(id: String) -> Self {
+        Self::new(id)
+    }
+}
+
+/// Produces a [`Task`] that focuses the next focusable widget inside the
+/// [`FocusScope`] with the given [`Id`], wrapping around at its end, and then
+/// applies the provided function to create a resulting task.
+///
+/// Widgets outside of the scope are never focused.
+pub fn focus_next_in<T, F>(scope: impl Into<Id>, f: F) -> Task<T>
+where
+    T: Send + 'static,
+    F: Send + Sync + Fn(widget::Id) -> Task<T> + 'static,
+{
+    focus_in(scope.into(), Direction::Next, f)
+}
+
+/// Produces a [`Task`] that focuses the previous focusable widget inside the
+/// [`FocusScope`] with the given [`Id`], wrapping around at its start, and
+/// then applies the provided function to create a resulting task.
+///
+/// Widgets outside of the scope are never focused.
+pub fn focus_previous_in<T, F>(scope: impl Into<Id>, f: F) -> Task<T>
+where
+    T: Send + 'static,
+    F: Send + Sync + Fn(widget::Id) -> Task<T> + 'static,
+{
+    focus_in(scope.into(), Direction::Previous, f)
+}
+
+fn focus_in<T, F>(scope: Id, direction: Direction, f: F) -> Task<T>
+where
+    T: Send + 'static,
+    F: Send + Sync + Fn(widget::Id) -> Task<T> + 'static,
+{
+    let focus = match direction {
+        Direction::Next => {
+            widget::operate(operation::focus_next(Some(scope.0)))
+        }
+        Direction::Previous => {
+            widget::operate(operation::focus_previous(Some(scope.0)))
+        }
+    };
+
+    focus.chain(
+        widget::operate(widget::operation::focusable::find_focused()).then(f),
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Next,
+    Previous,
+}
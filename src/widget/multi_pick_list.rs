@@ -0,0 +1,1013 @@
+//! Multi pick lists display a dropdown list of options that can be toggled.
+//!
+//! # Example
+//! ```no_run
+//! use iced::Element;
+//! use sweeten::widget::multi_pick_list;
+//!
+//! struct State {
+//!    toppings: Vec<Topping>,
+//! }
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! enum Topping {
+//!     Cheese,
+//!     Mushrooms,
+//!     Olives,
+//!     Pineapple,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ToppingsChanged(Vec<Topping>),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let toppings = [
+//!         Topping::Cheese,
+//!         Topping::Mushrooms,
+//!         Topping::Olives,
+//!         Topping::Pineapple,
+//!     ];
+//!
+//!     multi_pick_list(toppings, &state.toppings[..])
+//!         .on_change(Message::ToppingsChanged)
+//!         .disabled_if(|topping| {
+//!             matches!(topping, Topping::Pineapple).then_some("Out of stock")
+//!         })
+//!         .placeholder("Select your toppings...")
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::ToppingsChanged(toppings) => {
+//!             state.toppings = toppings;
+//!         }
+//!     }
+//! }
+//!
+//! impl std::fmt::Display for Topping {
+//!     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+//!         f.write_str(match self {
+//!             Self::Cheese => "Cheese",
+//!             Self::Mushrooms => "Mushrooms",
+//!             Self::Olives => "Olives",
+//!             Self::Pineapple => "Pineapple",
+//!         })
+//!     }
+//! }
+//! ```
+//
+// This widget is a modification of the original `PickList` widget from [`iced`]
+//
+// [`iced`]: https://github.com/iced-rs/iced
+//
+// Copyright 2019 Héctor Ramón, Iced contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+use iced::advanced::text::{self, paragraph, Text};
+use iced::advanced::widget::operation::{Focusable, Operation};
+use iced::advanced::widget::tree::{self, Tree};
+use iced::advanced::{
+    layout, mouse, overlay, renderer, Clipboard, Layout, Shell, Widget,
+};
+use iced::alignment;
+use iced::event::{self, Event};
+use iced::keyboard::{self, key};
+use iced::time::Instant;
+use iced::touch;
+use iced::{Element, Length, Padding, Pixels, Point, Rectangle, Size, Vector};
+
+use std::borrow::{Borrow, Cow};
+use std::f32;
+
+use crate::widget::operation::TabIndex;
+use crate::widget::overlay::menu::{self, Menu};
+use crate::widget::pick_list::{
+    self, draw_handle, find_by_prefix, Catalog, Handle, Status, Style, StyleFn,
+    TypeAhead,
+};
+
+/// A widget for selecting any number of values from a list of options.
+///
+/// The menu of a [`MultiPickList`] stays open while its options are toggled,
+/// showing a checkmark next to the selected ones.
+///
+/// # Example
+/// ```no_run
+/// use iced::Element;
+/// use sweeten::widget::multi_pick_list;
+///
+/// struct State {
+///    toppings: Vec<Topping>,
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Topping {
+///     Cheese,
+///     Mushrooms,
+///     Olives,
+///     Pineapple,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ToppingToggled(Topping, bool),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     let toppings = [
+///         Topping::Cheese,
+///         Topping::Mushrooms,
+///         Topping::Olives,
+///         Topping::Pineapple,
+///     ];
+///
+///     multi_pick_list(toppings, &state.toppings[..])
+///         .on_toggle(Message::ToppingToggled)
+///         .placeholder("Select your toppings...")
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::ToppingToggled(topping, true) => {
+///             state.toppings.push(topping);
+///         }
+///         Message::ToppingToggled(topping, false) => {
+///             state.toppings.retain(|selected| *selected != topping);
+///         }
+///     }
+/// }
+///
+/// impl std::fmt::Display for Topping {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str(match self {
+///             Self::Cheese => "Cheese",
+///             Self::Mushrooms => "Mushrooms",
+///             Self::Olives => "Olives",
+///             Self::Pineapple => "Pineapple",
+///         })
+///     }
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+#[allow(clippy::type_complexity)]
+pub struct MultiPickList<
+    'a,
+    T,
+    L,
+    V,
+    Message,
+    Theme = iced::Theme,
+    Renderer = iced::Renderer,
+> where
+    T: ToString + PartialEq + Clone,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<[T]> + 'a,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    on_change: Option<OnChange<'a, T, Message>>,
+    on_open: Option<Message>,
+    on_close: Option<Message>,
+    options: L,
+    disabled: Option<Vec<bool>>,
+    disabled_reasons: Vec<Option<String>>,
+    placeholder: Option<String>,
+    selected: V,
+    summary: Option<Box<dyn Fn(&[T]) -> String + 'a>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    handle: Handle<Renderer::Font>,
+    tab_index: i32,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+/// The message produced when an option of a [`MultiPickList`] is toggled.
+#[allow(clippy::type_complexity)]
+enum OnChange<'a, T, Message> {
+    Toggle(Box<dyn Fn(T, bool) -> Message + 'a>),
+    Selection(Box<dyn Fn(Vec<T>) -> Message + 'a>),
+}
+
+impl<T, Message> OnChange<'_, T, Message>
+where
+    T: PartialEq + Clone,
+{
+    /// Produces the message for toggling the given `option`.
+    fn message(&self, options: &[T], selected: &[T], option: T) -> Message {
+        let is_selected = !selected.contains(&option);
+
+        match self {
+            Self::Toggle(on_toggle) => on_toggle(option, is_selected),
+            Self::Selection(on_change) => on_change(
+                options
+                    .iter()
+                    .filter(|candidate| {
+                        if **candidate == option {
+                            is_selected
+                        } else {
+                            selected.contains(candidate)
+                        }
+                    })
+                    .cloned()
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer>
+    MultiPickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<[T]> + 'a,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`MultiPickList`] with the given list of options and the
+    /// current selected values.
+    ///
+    /// The [`MultiPickList`] cannot be opened until either
+    /// [`on_toggle`](Self::on_toggle) or [`on_change`](Self::on_change) is
+    /// set.
+    pub fn new(options: L, selected: V) -> Self {
+        Self {
+            on_change: None,
+            on_open: None,
+            on_close: None,
+            options,
+            disabled: None,
+            disabled_reasons: Vec::new(),
+            placeholder: None,
+            selected,
+            summary: None,
+            width: Length::Shrink,
+            padding: pick_list::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::default(),
+            font: None,
+            handle: Handle::default(),
+            tab_index: 0,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+        }
+    }
+
+    /// Sets the message that will be produced when an option is toggled,
+    /// along with whether it is now selected.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(T, bool) -> Message + 'a,
+    ) -> Self {
+        self.on_change = Some(OnChange::Toggle(Box::new(on_toggle)));
+        self
+    }
+
+    /// Sets the message that will be produced when an option is toggled,
+    /// along with the new selection in the order of the options.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(Vec<T>) -> Message + 'a,
+    ) -> Self {
+        self.on_change = Some(OnChange::Selection(Box::new(on_change)));
+        self
+    }
+
+    /// Sets the placeholder of the [`MultiPickList`], shown while nothing is
+    /// selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Disables the options of the [`MultiPickList`] flagged by the given
+    /// function, which receives all the options at once.
+    pub fn disabled(
+        mut self,
+        disabled: impl FnOnce(&[T]) -> Vec<bool>,
+    ) -> Self {
+        self.disabled = Some(disabled(self.options.borrow()));
+        self.disabled_reasons.clear();
+        self
+    }
+
+    /// Disables the options of the [`MultiPickList`] for which the given
+    /// function returns a reason.
+    ///
+    /// The function is called once for each option when the
+    /// [`MultiPickList`] is built. Unless it is empty, the reason is shown
+    /// next to a disabled option while it is hovered in the menu.
+    pub fn disabled_if<R: Into<String>>(
+        mut self,
+        reason: impl Fn(&T) -> Option<R>,
+    ) -> Self {
+        let (disabled, reasons) = self
+            .options
+            .borrow()
+            .iter()
+            .map(|option| {
+                let reason = reason(option).map(Into::into);
+
+                (reason.is_some(), reason.filter(|reason| !reason.is_empty()))
+            })
+            .unzip();
+
+        self.disabled = Some(disabled);
+        self.disabled_reasons = reasons;
+        self
+    }
+
+    /// Sets the function that describes the selected values in the closed
+    /// [`MultiPickList`].
+    ///
+    /// By default, the selected values are listed with commas, ending with a
+    /// count of the ones that do not fit.
+    pub fn summary(mut self, summary: impl Fn(&[T]) -> String + 'a) -> Self {
+        self.summary = Some(Box::new(summary));
+        self
+    }
+
+    /// Sets the width of the [`MultiPickList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`MultiPickList`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MultiPickList`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`MultiPickList`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`MultiPickList`].
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the [`MultiPickList`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Handle`] of the [`MultiPickList`].
+    pub fn handle(mut self, handle: Handle<Renderer::Font>) -> Self {
+        self.handle = handle;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`MultiPickList`] is
+    /// opened.
+    pub fn on_open(mut self, on_open: Message) -> Self {
+        self.on_open = Some(on_open);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`MultiPickList`] is
+    /// closed.
+    ///
+    /// The menu stays open while options are toggled, so this is the place to
+    /// commit a batch of toggles at once.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Sets the position of the [`MultiPickList`] in the keyboard navigation
+    /// order of [`focus_next`] and [`focus_previous`].
+    ///
+    /// Negative values remove the [`MultiPickList`] from the navigation
+    /// order, but it can still be focused by a click. See [`TabIndex`] for
+    /// details.
+    ///
+    /// [`focus_next`]: crate::widget::operation::focus_next
+    /// [`focus_previous`]: crate::widget::operation::focus_previous
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    /// Sets the style of the [`MultiPickList`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the [`Menu`].
+    #[must_use]
+    pub fn menu_style(
+        mut self,
+        style: impl Fn(&Theme) -> menu::Style + 'a,
+    ) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MultiPickList`].
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the [`Menu`].
+    #[must_use]
+    pub fn menu_class(
+        mut self,
+        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    /// Returns whether the option at the given index is disabled.
+    fn is_disabled(&self, index: usize) -> bool {
+        self.disabled
+            .as_ref()
+            .is_some_and(|disabled| disabled.get(index) == Some(&true))
+    }
+
+    /// Produces the message for toggling the option at the given `index`,
+    /// unless it is disabled.
+    fn toggle(&self, index: usize) -> Option<Message> {
+        let on_change = self.on_change.as_ref()?;
+        let options = self.options.borrow();
+        let option = options.get(index)?;
+
+        if self.is_disabled(index) {
+            return None;
+        }
+
+        Some(on_change.message(options, self.selected.borrow(), option.clone()))
+    }
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MultiPickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'a,
+    L: Borrow<[T]>,
+    V: Borrow<[T]>,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::new())
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.custom(&mut TabIndex(self.tab_index), None);
+        operation.focusable(state, None);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let options = self.options.borrow();
+        let selected = self.selected.borrow();
+
+        let option_text = Text {
+            content: "",
+            bounds: Size::new(
+                f32::INFINITY,
+                self.text_line_height.to_absolute(text_size).into(),
+            ),
+            size: text_size,
+            line_height: self.text_line_height,
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::default(),
+        };
+
+        if let Some(placeholder) = &self.placeholder {
+            state.placeholder.update(Text {
+                content: placeholder,
+                ..option_text
+            });
+        }
+
+        let max_width = match self.width {
+            Length::Shrink => {
+                state.options.resize_with(options.len(), Default::default);
+
+                for (option, paragraph) in
+                    options.iter().zip(state.options.iter_mut())
+                {
+                    let label = option.to_string();
+
+                    paragraph.update(Text {
+                        content: &label,
+                        ..option_text
+                    });
+                }
+
+                let labels_width =
+                    state.options.iter().fold(0.0, |width, paragraph| {
+                        f32::max(width, paragraph.min_width())
+                    });
+
+                labels_width.max(
+                    self.placeholder
+                        .as_ref()
+                        .map(|_| state.placeholder.min_width())
+                        .unwrap_or(0.0),
+                )
+            }
+            _ => 0.0,
+        };
+
+        let size = {
+            let intrinsic = Size::new(
+                max_width + text_size.0 + self.padding.left,
+                f32::from(self.text_line_height.to_absolute(text_size)),
+            );
+
+            limits
+                .width(self.width)
+                .shrink(self.padding)
+                .resolve(self.width, Length::Shrink, intrinsic)
+                .expand(self.padding)
+        };
+
+        state.summary = if let Some(summary) = &self.summary {
+            summary(selected)
+        } else {
+            let available = size.width
+                - self.padding.horizontal()
+                - text_size.0
+                - self.padding.left;
+            let labels: Vec<_> =
+                selected.iter().map(ToString::to_string).collect();
+
+            let summary = |shown: usize| {
+                let hidden = labels.len() - shown;
+                let list = labels[..shown].join(", ");
+
+                if hidden == 0 {
+                    list
+                } else {
+                    format!("{list}, +{hidden} more")
+                }
+            };
+
+            let mut fits = |summary: &str| {
+                state.measure.update(Text {
+                    content: summary,
+                    ..option_text
+                });
+
+                state.measure.min_width() <= available
+            };
+
+            // List as many values as fit, counting the rest. The summary
+            // grows with every value listed, so they are found by bisection.
+            let (mut fitting, mut overflowing) = (0, labels.len() + 1);
+
+            while overflowing - fitting > 1 {
+                let shown = (fitting + overflowing) / 2;
+
+                if fits(&summary(shown)) {
+                    fitting = shown;
+                } else {
+                    overflowing = shown;
+                }
+            }
+
+            if fitting == 0 {
+                format!("{} selected", labels.len())
+            } else {
+                summary(fitting)
+            }
+        };
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if state.is_open {
+                    // Event wasn't processed by the overlay, so the cursor
+                    // was clicked outside of the menu and we close it.
+                    state.is_open = false;
+                    state.is_focused = cursor.is_over(layout.bounds());
+
+                    if let Some(on_close) = &self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+
+                    event::Status::Captured
+                } else if cursor.is_over(layout.bounds())
+                    && self.on_change.is_some()
+                {
+                    let selected = self.selected.borrow();
+
+                    state.is_open = true;
+                    state.is_focused = true;
+                    state.hovered_option = self
+                        .options
+                        .borrow()
+                        .iter()
+                        .position(|option| selected.contains(option));
+
+                    if let Some(index) = state.hovered_option {
+                        state.menu.scroll_to(index);
+                    }
+
+                    if let Some(on_open) = &self.on_open {
+                        shell.publish(on_open.clone());
+                    }
+
+                    event::Status::Captured
+                } else {
+                    state.unfocus();
+
+                    event::Status::Ignored
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if !state.is_open && state.is_focused => {
+                state.unfocus();
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.is_open => match key {
+                key::Named::Enter | key::Named::Space => {
+                    if let Some(message) = state
+                        .hovered_option
+                        .and_then(|index| self.toggle(index))
+                    {
+                        shell.publish(message);
+                    }
+
+                    event::Status::Captured
+                }
+                key::Named::Escape => {
+                    state.is_open = false;
+                    state.unfocus();
+
+                    if let Some(on_close) = &self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+
+                    event::Status::Captured
+                }
+                key => {
+                    if state.menu.navigate(
+                        key,
+                        &mut state.hovered_option,
+                        self.options.borrow().len(),
+                        |index| self.is_disabled(index),
+                    ) {
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    }
+                }
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                text: Some(text),
+                modifiers,
+                ..
+            }) if state.is_open => {
+                if modifiers.command()
+                    || modifiers.alt()
+                    || text.chars().any(char::is_control)
+                {
+                    return event::Status::Ignored;
+                }
+
+                let options = self.options.borrow();
+                let disabled: Vec<bool> =
+                    (0..options.len()).map(|i| self.is_disabled(i)).collect();
+                let prefix = state.type_ahead.push(&text, Instant::now());
+
+                if let Some(index) = find_by_prefix(
+                    |index| options[index].to_string(),
+                    &disabled,
+                    state.hovered_option,
+                    prefix,
+                ) {
+                    state.hovered_option = Some(index);
+                    state.menu.scroll_to(index);
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let is_mouse_over = cursor.is_over(bounds);
+
+        if is_mouse_over && self.on_change.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let is_mouse_over = cursor.is_over(bounds);
+        let is_empty = self.selected.borrow().is_empty();
+
+        let status = if state.is_open {
+            Status::Opened
+        } else if state.is_focused {
+            Status::Focused
+        } else if is_mouse_over && self.on_change.is_some() {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = Catalog::style(theme, &self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        draw_handle(
+            renderer,
+            &self.handle,
+            state.is_open,
+            bounds,
+            self.padding,
+            style.handle_color,
+            viewport,
+        );
+
+        let label = if is_empty {
+            self.placeholder.clone()
+        } else {
+            Some(state.summary.clone())
+        };
+
+        if let Some(label) = label {
+            let text_size =
+                self.text_size.unwrap_or_else(|| renderer.default_size());
+
+            renderer.fill_text(
+                Text {
+                    content: label,
+                    size: text_size,
+                    line_height: self.text_line_height,
+                    font,
+                    bounds: Size::new(
+                        bounds.width - self.padding.horizontal(),
+                        f32::from(self.text_line_height.to_absolute(text_size)),
+                    ),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: self.text_shaping,
+                    wrapping: text::Wrapping::default(),
+                },
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                if is_empty {
+                    style.placeholder_color
+                } else {
+                    style.text_color
+                },
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let on_change = self.on_change.as_ref().filter(|_| state.is_open)?;
+
+        let bounds = layout.bounds();
+        let options = self.options.borrow();
+        let selected = self.selected.borrow();
+        let checked = options
+            .iter()
+            .map(|option| selected.contains(option))
+            .collect();
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            options,
+            &mut state.hovered_option,
+            |option| on_change.message(options, selected, option),
+            self.disabled.as_deref().map(Cow::Borrowed),
+            None,
+            &self.menu_class,
+        )
+        .disabled_reasons(self.disabled_reasons.as_slice())
+        .checked(checked)
+        .width(bounds.width)
+        .padding(self.padding)
+        .font(font)
+        .text_shaping(self.text_shaping);
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(layout.position() + translation, bounds.height))
+    }
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer>
+    From<MultiPickList<'a, T, L, V, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'a,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<[T]> + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        multi_pick_list: MultiPickList<'a, T, L, V, Message, Theme, Renderer>,
+    ) -> Self {
+        Self::new(multi_pick_list)
+    }
+}
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    menu: menu::State,
+    is_open: bool,
+    is_focused: bool,
+    hovered_option: Option<usize>,
+    type_ahead: TypeAhead,
+    summary: String,
+    options: Vec<paragraph::Plain<P>>,
+    placeholder: paragraph::Plain<P>,
+    measure: paragraph::Plain<P>,
+}
+
+impl<P: text::Paragraph> State<P> {
+    /// Creates a new [`State`] for a [`MultiPickList`].
+    fn new() -> Self {
+        Self {
+            menu: menu::State::default(),
+            is_open: bool::default(),
+            is_focused: bool::default(),
+            hovered_option: Option::default(),
+            type_ahead: TypeAhead::default(),
+            summary: String::new(),
+            options: Vec::new(),
+            placeholder: paragraph::Plain::default(),
+            measure: paragraph::Plain::default(),
+        }
+    }
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: text::Paragraph> Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.type_ahead = TypeAhead::default();
+    }
+}
@@ -0,0 +1,255 @@
+//! Operate on the focusable widgets of a widget tree.
+//!
+//! A widget can take part in an explicit keyboard navigation order by
+//! announcing its [`TabIndex`] through [`Operation::custom`] right before
+//! calling [`Operation::focusable`].
+use iced::advanced::widget::operation::{Focusable, Operation, Outcome};
+use iced::advanced::widget::Id;
+use iced::Rectangle;
+
+use std::any::Any;
+
+use crate::widget::text_input::{self, FocusReason};
+
+/// The position of a focusable widget in the keyboard navigation order.
+///
+/// Widgets with a positive index come first, in ascending order, followed by
+/// widgets with an index of zero in tree order. Widgets with a negative index
+/// are skipped by [`focus_next`] and [`focus_previous`], but can still be
+/// focused by a click or by their [`Id`]. Once focused, they move the focus
+/// to their neighbors in tree order, as if their index was zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct TabIndex(pub i32);
+
+impl TabIndex {
+    fn sort_key(self) -> Option<(bool, i32)> {
+        match self.0 {
+            index if index > 0 => Some((false, index)),
+            0 => Some((true, 0)),
+            _ => None,
+        }
+    }
+}
+
+/// Produces an [`Operation`] that focuses the widget following the focused
+/// one in the keyboard navigation order.
+///
+/// If `scope` is given, only the focusable widgets inside the container with
+/// that [`Id`] are considered, and the focus wraps around at the end.
+/// Otherwise, the focus is cleared after the last widget.
+pub fn focus_next<T>(scope: Option<Id>) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    Count::new(scope, Direction::Next)
+}
+
+/// Produces an [`Operation`] that focuses the widget preceding the focused
+/// one in the keyboard navigation order.
+///
+/// If `scope` is given, only the focusable widgets inside the container with
+/// that [`Id`] are considered, and the focus wraps around at the start.
+/// Otherwise, the focus is cleared before the first widget.
+pub fn focus_previous<T>(scope: Option<Id>) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    Count::new(scope, Direction::Previous)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Next,
+    Previous,
+}
+
+/// Tracks whether a traversal is inside the scope of an operation.
+#[derive(Debug)]
+struct Scope {
+    id: Option<Id>,
+    depth: usize,
+}
+
+impl Scope {
+    fn new(id: Option<Id>) -> Self {
+        Self { id, depth: 0 }
+    }
+
+    fn contains_current(&self) -> bool {
+        self.id.is_none() || self.depth > 0
+    }
+
+    fn is(&self, id: Option<&Id>) -> bool {
+        self.id.is_some() && id == self.id.as_ref()
+    }
+}
+
+struct Entry {
+    tab_index: TabIndex,
+    is_focused: bool,
+}
+
+/// Collects the focusable widgets in scope, and then chains the [`Apply`]
+/// operation that moves the focus.
+struct Count {
+    scope: Scope,
+    direction: Direction,
+    pending: TabIndex,
+    entries: Vec<Entry>,
+}
+
+impl Count {
+    fn new(scope: Option<Id>, direction: Direction) -> Self {
+        Self {
+            scope: Scope::new(scope),
+            direction,
+            pending: TabIndex::default(),
+            entries: Vec::new(),
+        }
+    }
+
+    fn target(&self) -> Option<usize> {
+        let mut order: Vec<usize> = (0..self.entries.len())
+            .filter(|&i| self.entries[i].tab_index.sort_key().is_some())
+            .collect();
+
+        order.sort_by_key(|&i| self.entries[i].tab_index.sort_key());
+
+        if order.is_empty() {
+            return None;
+        }
+
+        let wraps = self.scope.id.is_some();
+        let focused = self.entries.iter().position(|entry| entry.is_focused);
+
+        let Some(focused) = focused else {
+            return Some(match self.direction {
+                Direction::Next => order[0],
+                Direction::Previous => order[order.len() - 1],
+            });
+        };
+
+        // A focused widget left out of the order stands where an index of
+        // zero would put it.
+        let key = |i: usize| {
+            let tab_index = self.entries[i].tab_index;
+
+            (tab_index.sort_key().unwrap_or((true, 0)), i)
+        };
+
+        let start = order.partition_point(|&i| key(i) < key(focused));
+        let end = start + usize::from(order.get(start) == Some(&focused));
+
+        let position = match self.direction {
+            Direction::Next if end < order.len() => Some(end),
+            Direction::Next => wraps.then_some(0),
+            Direction::Previous if start > 0 => Some(start - 1),
+            Direction::Previous => wraps.then_some(order.len() - 1),
+        };
+
+        position.map(|position| order[position])
+    }
+}
+
+impl<T> Operation<T> for Count
+where
+    T: Send + 'static,
+{
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let is_scope = self.scope.is(id);
+
+        self.scope.depth += usize::from(is_scope);
+        operate_on_children(self);
+        self.scope.depth -= usize::from(is_scope);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+        if let Some(tab_index) = state.downcast_ref::<TabIndex>() {
+            self.pending = *tab_index;
+        }
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        let tab_index = std::mem::take(&mut self.pending);
+
+        if self.scope.contains_current() {
+            self.entries.push(Entry {
+                tab_index,
+                is_focused: state.is_focused(),
+            });
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        Outcome::Chain(Box::new(Apply {
+            scope: Scope::new(self.scope.id.clone()),
+            target: self.target(),
+            current: 0,
+            is_focusing: false,
+        }))
+    }
+}
+
+/// Focuses the target widget in scope and unfocuses every other widget, so
+/// only one widget is ever focused.
+///
+/// The focus is attributed to the keyboard as soon as it is applied, so the
+/// widget reports the right [`FocusReason`] whenever it publishes it.
+struct Apply {
+    scope: Scope,
+    target: Option<usize>,
+    current: usize,
+    is_focusing: bool,
+}
+
+impl<T> Operation<T> for Apply {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let is_scope = self.scope.is(id);
+
+        self.scope.depth += usize::from(is_scope);
+        operate_on_children(self);
+        self.scope.depth -= usize::from(is_scope);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+        // Widgets expose their focus state right after being focusable.
+        if std::mem::take(&mut self.is_focusing) {
+            text_input::mark_focus_reason(state, FocusReason::Keyboard);
+        }
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        self.is_focusing = false;
+
+        if !self.scope.contains_current() {
+            // The focus only leaves the rest of the tree once it has
+            // somewhere to go in scope.
+            if self.target.is_some() && state.is_focused() {
+                state.unfocus();
+            }
+
+            return;
+        }
+
+        if Some(self.current) == self.target {
+            if !state.is_focused() {
+                state.focus();
+                self.is_focusing = true;
+            }
+        } else if state.is_focused() {
+            state.unfocus();
+        }
+
+        self.current += 1;
+    }
+}
@@ -22,20 +22,26 @@
 // COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
-use iced::advanced::text::{self, Text};
-use iced::advanced::widget::Tree;
+use iced::advanced::text::{self, Paragraph as _, Text};
+use iced::advanced::widget::{operation, Id, Operation, Tree};
 use iced::advanced::{layout, mouse, overlay, renderer, Clipboard, Layout};
 use iced::advanced::{Shell, Widget};
 use iced::alignment;
 use iced::border::{self, Border};
 use iced::event::{self, Event};
+use iced::keyboard::{self, key};
+use iced::time::{Duration, Instant};
 use iced::touch;
-use iced::widget::scrollable::{self, Scrollable};
+use iced::widget::scrollable::{self, AbsoluteOffset, Scrollable};
+use iced::window;
 use iced::{
     Background, Color, Element, Length, Padding, Pixels, Point, Rectangle,
     Size, Theme, Vector,
 };
 
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
 /// A list of selectable options.
 #[allow(missing_debug_implementations)]
 pub struct Menu<
@@ -46,16 +52,26 @@ pub struct Menu<
     Theme = iced::Theme,
     Renderer = iced::Renderer,
 > where
+    T: Clone,
     Theme: Catalog,
     Renderer: text::Renderer,
     'b: 'a,
 {
     state: &'a mut State,
-    options: &'a [T],
-    disabled: Option<Vec<bool>>,
+    options: Options<'a, T>,
+    disabled: Option<Cow<'a, [bool]>>,
+    disabled_reasons: Cow<'a, [Option<String>]>,
+    checked: Option<Vec<bool>>,
+    sections: Vec<(usize, String)>,
+    option_view: Option<OptionView<'a, T, Message, Theme, Renderer>>,
+    is_virtualized: bool,
+    is_loading: bool,
+    search: Option<Search<'a, Theme, Renderer>>,
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    on_scroll_end: Option<Message>,
+    on_close: Option<Message>,
     width: f32,
     padding: Padding,
     text_size: Option<Pixels>,
@@ -81,17 +97,29 @@ where
         options: &'a [T],
         hovered_option: &'a mut Option<usize>,
         on_selected: impl FnMut(T) -> Message + 'a,
-        disabled: Option<Vec<bool>>,
+        disabled: Option<Cow<'a, [bool]>>,
         on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
         class: &'a <Theme as Catalog>::Class<'b>,
     ) -> Self {
         Menu {
             state,
-            options,
+            options: Options {
+                all: options,
+                shown: None,
+            },
             disabled,
+            disabled_reasons: Cow::Borrowed(&[]),
+            checked: None,
+            sections: Vec::new(),
+            option_view: None,
+            is_virtualized: false,
+            is_loading: false,
+            search: None,
             hovered_option,
             on_selected: Box::new(on_selected),
             on_option_hovered,
+            on_scroll_end: None,
+            on_close: None,
             width: 0.0,
             padding: Padding::ZERO,
             text_size: None,
@@ -102,6 +130,111 @@ where
         }
     }
 
+    /// Shows only the options of the [`Menu`] at the given indices, in order.
+    ///
+    /// Everything else aligned to the options of the [`Menu`], like its
+    /// disabled flags or its sections, is aligned to the shown ones instead.
+    pub fn shown(mut self, indices: &'a [usize]) -> Self {
+        self.options.shown = Some(indices);
+        self
+    }
+
+    /// Sets the reasons why the options of the [`Menu`] are disabled, aligned
+    /// to them.
+    ///
+    /// The reason of a disabled option is shown at the end of its row while
+    /// it is hovered.
+    pub fn disabled_reasons(
+        mut self,
+        reasons: impl Into<Cow<'a, [Option<String>]>>,
+    ) -> Self {
+        self.disabled_reasons = reasons.into();
+        self
+    }
+
+    /// Shows a checkmark next to each option of the [`Menu`] that is
+    /// checked, as given by the flags aligned to its options.
+    pub fn checked(mut self, checked: Vec<bool>) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Divides the options of the [`Menu`] into sections, each given by the
+    /// index of its first option and the label of its header.
+    ///
+    /// Headers are skipped when hovering, selecting or navigating options.
+    pub fn sections(mut self, sections: Vec<(usize, String)>) -> Self {
+        self.sections = sections;
+        self
+    }
+
+    /// Sets the function that produces the [`Element`] shown for each option
+    /// of the [`Menu`], in place of its label.
+    ///
+    /// The elements are drawn with the text color of the status of their
+    /// option, but do not receive events.
+    pub fn option_view(
+        mut self,
+        view: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        self.option_view = Some(Box::new(view));
+        self
+    }
+
+    /// Sets whether the [`Menu`] only lays out the rows in view.
+    ///
+    /// A virtualized [`Menu`] gives every row the height of a line of text,
+    /// so its size is known without going through its options. Option views
+    /// are then built and laid out as their rows are drawn.
+    pub fn virtualized(mut self, is_virtualized: bool) -> Self {
+        self.is_virtualized = is_virtualized;
+        self
+    }
+
+    /// Sets whether more options of the [`Menu`] are being fetched.
+    ///
+    /// A loading [`Menu`] shows a spinner after its options, which keep
+    /// being hovered and selected as usual while more of them arrive.
+    pub fn loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
+    /// Sets the message produced when the [`Menu`] is scrolled to the end of
+    /// its options, which can be used to fetch more of them.
+    ///
+    /// The message is produced once for every amount of options, and not
+    /// while the [`Menu`] is loading. It is produced once the list is
+    /// scrolled down to its end, or right away if its options do not fill
+    /// the [`Menu`].
+    pub fn on_scroll_end(mut self, on_scroll_end: Message) -> Self {
+        self.on_scroll_end = Some(on_scroll_end);
+        self
+    }
+
+    /// Sets the message produced right after an option is selected, for
+    /// owners that close the [`Menu`] once an option is chosen.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Places a search field at the top of the [`Menu`].
+    ///
+    /// The field must produce its new value as its message, which replaces
+    /// the given `query`.
+    pub fn search(
+        mut self,
+        field: impl Into<Element<'a, String, Theme, Renderer>>,
+        query: &'a mut String,
+    ) -> Self {
+        self.search = Some(Search {
+            field: field.into(),
+            query,
+        });
+        self
+    }
+
     /// Sets the width of the [`Menu`].
     pub fn width(mut self, width: f32) -> Self {
         self.width = width;
@@ -164,6 +297,14 @@ where
 #[derive(Debug)]
 pub struct State {
     tree: Tree,
+    search: Tree,
+    scroll_target: Option<usize>,
+    page_size: usize,
+    is_search_pending_focus: bool,
+    scrolled_to_end: Option<usize>,
+    scroll_offset: Option<f32>,
+    loading_since: Option<Instant>,
+    loading_elapsed: Duration,
 }
 
 impl State {
@@ -171,8 +312,83 @@ impl State {
     pub fn new() -> Self {
         Self {
             tree: Tree::empty(),
+            search: Tree::empty(),
+            scroll_target: None,
+            page_size: 1,
+            is_search_pending_focus: false,
+            scrolled_to_end: None,
+            scroll_offset: None,
+            loading_since: None,
+            loading_elapsed: Duration::ZERO,
         }
     }
+
+    /// Focuses the search field of the [`Menu`] the next time it is laid
+    /// out.
+    pub(crate) fn focus_search(&mut self) {
+        self.is_search_pending_focus = true;
+    }
+
+    /// Scrolls the option at the given index into view the next time the
+    /// [`Menu`] is laid out.
+    pub(crate) fn scroll_to(&mut self, option: usize) {
+        self.scroll_target = Some(option);
+    }
+
+    /// Moves the `hovered` option in response to the arrow keys, Home/End
+    /// or PageUp/PageDown, skipping disabled options, and scrolls it into
+    /// view.
+    ///
+    /// Returns whether the key navigates the [`Menu`].
+    pub(crate) fn navigate(
+        &mut self,
+        key: key::Named,
+        hovered: &mut Option<usize>,
+        options: usize,
+        is_disabled: impl Fn(usize) -> bool,
+    ) -> bool {
+        let is_enabled = |index: &usize| !is_disabled(*index);
+        let forward = |start: usize| (start..options).find(is_enabled);
+        let backward = |end: usize| {
+            (0..=end.min(options.checked_sub(1)?))
+                .rev()
+                .find(is_enabled)
+        };
+
+        let last = options.saturating_sub(1);
+        let page = self.page_size;
+
+        let target = match key {
+            key::Named::ArrowDown => {
+                forward(hovered.map_or(0, |index| index + 1))
+            }
+            key::Named::ArrowUp => match *hovered {
+                Some(index) => index.checked_sub(1).and_then(backward),
+                None => backward(last),
+            },
+            key::Named::Home => forward(0),
+            key::Named::End => backward(last),
+            key::Named::PageDown => {
+                let start = hovered.map_or(0, |index| index + page).min(last);
+
+                forward(start).or_else(|| backward(start))
+            }
+            key::Named::PageUp => {
+                let start =
+                    hovered.map_or(0, |index| index.saturating_sub(page));
+
+                backward(start).or_else(|| forward(start))
+            }
+            _ => return false,
+        };
+
+        if let Some(index) = target {
+            *hovered = Some(index);
+            self.scroll_to(index);
+        }
+
+        true
+    }
 }
 
 impl Default for State {
@@ -191,6 +407,7 @@ where
     /// Calculate the index of an option based on a cursor position within the list bounds
     fn option_index_at(
         &self,
+        layout: Layout<'_>,
         cursor_position: Point,
         renderer: &Renderer,
     ) -> Option<usize> {
@@ -200,12 +417,60 @@ where
             f32::from(self.text_line_height.to_absolute(text_size))
                 + self.padding.vertical();
 
-        let index = (cursor_position.y / option_height) as usize;
+        // Rows with views may have different heights, so they are laid out.
+        let row = if self.views.is_empty() {
+            (cursor_position.y / option_height) as usize
+        } else {
+            let y = layout.bounds().y + cursor_position.y;
+
+            layout.children().position(|row| {
+                let bounds = row.bounds();
 
-        if index < self.options.len() {
-            Some(index)
+                y < bounds.y + bounds.height
+            })?
+        };
+
+        match self.row(row) {
+            Some(Row::Option(index)) => Some(index),
+            Some(Row::Header(_) | Row::Loading) | None => None,
+        }
+    }
+
+    /// Returns the horizontal space before the label of an option.
+    fn inset(&self, text_size: Pixels) -> f32 {
+        if self.checked.is_some() {
+            self.padding.left * 2.0 + text_size.0
         } else {
-            None
+            self.padding.left
+        }
+    }
+
+    /// Returns the amount of rows in the list, counting section headers
+    /// and the loading spinner.
+    fn rows(&self) -> usize {
+        self.options.len()
+            + self.sections.len()
+            + usize::from(self.loading.is_some())
+    }
+
+    /// Returns what is shown in the given row of the list.
+    fn row(&self, row: usize) -> Option<Row<'_>> {
+        let mut headers = 0;
+
+        for (start, label) in &self.sections {
+            match (start + headers).cmp(&row) {
+                Ordering::Less => headers += 1,
+                Ordering::Equal => return Some(Row::Header(label)),
+                Ordering::Greater => break,
+            }
+        }
+
+        let index = row - headers;
+
+        match index.cmp(&self.options.len()) {
+            Ordering::Less => Some(Row::Option(index)),
+            Ordering::Equal if self.loading.is_some() => Some(Row::Loading),
+            Ordering::Equal | Ordering::Greater => None,
         }
     }
 
@@ -217,6 +482,18 @@ where
             .copied()
             .unwrap_or(false)
     }
+
+    /// Publish the selection of the option at the given index, followed by
+    /// `on_close`
+    fn select(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(option) = self.options.get(index) {
+            shell.publish((self.on_selected)(option.clone()));
+
+            if let Some(on_close) = self.on_close.take() {
+                shell.publish(on_close);
+            }
+        }
+    }
 }
 
 struct Overlay<'a, 'b, Message, Theme, Renderer>
@@ -225,10 +502,18 @@ where
     Renderer: renderer::Renderer,
 {
     position: Point,
-    state: &'a mut Tree,
+    state: &'a mut State,
     list: Scrollable<'a, Message, Theme, Renderer>,
+    search: Option<Search<'a, Theme, Renderer>>,
+    sections: Vec<usize>,
+    option_count: usize,
+    is_loading: bool,
+    on_scroll_end: Option<Message>,
     width: f32,
     target_height: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -251,9 +536,18 @@ where
             state,
             options,
             disabled,
+            disabled_reasons,
+            checked,
+            mut sections,
+            option_view,
+            is_virtualized,
+            is_loading,
+            search,
             hovered_option,
             on_selected,
             on_option_hovered,
+            on_scroll_end,
+            on_close,
             width,
             padding,
             font,
@@ -263,12 +557,38 @@ where
             class,
         } = menu;
 
+        sections.retain(|(start, _)| *start < options.len());
+        sections.sort_by_key(|(start, _)| *start);
+        sections.dedup_by_key(|(start, _)| *start);
+
+        let starts = sections.iter().map(|(start, _)| *start).collect();
+        let option_count = options.len();
+
+        if !is_loading {
+            state.loading_since = None;
+            state.loading_elapsed = Duration::ZERO;
+        }
+
+        let (views, option_view) = match option_view {
+            Some(view) if !is_virtualized => {
+                (options.iter().map(view).collect(), None)
+            }
+            option_view => (Vec::new(), option_view),
+        };
+
         let list = Scrollable::new(List {
             options,
             disabled,
+            disabled_reasons,
+            checked,
+            sections,
+            views,
+            option_view,
+            loading: is_loading.then_some(state.loading_elapsed),
             hovered_option,
             on_selected,
             on_option_hovered,
+            on_close,
             font,
             text_size,
             text_line_height,
@@ -279,17 +599,58 @@ where
 
         state.tree.diff(&list as &dyn Widget<_, _, _>);
 
+        if let Some(search) = &search {
+            state.search.diff(search.field.as_widget());
+        }
+
         Self {
             position,
-            state: &mut state.tree,
+            state,
             list,
+            search,
+            sections: starts,
+            option_count,
+            is_loading,
+            on_scroll_end,
             width,
             target_height,
+            padding,
+            text_size,
+            text_line_height,
             class,
         }
     }
 }
 
+impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the height of an option of the [`Menu`], unless it has a view.
+    fn option_height(&self, renderer: &Renderer) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.text_line_height.to_absolute(text_size))
+            + self.padding.vertical()
+    }
+
+    /// Returns how far the list of the [`Menu`] is scrolled.
+    fn scroll_position(
+        &mut self,
+        list: Layout<'_>,
+        renderer: &Renderer,
+    ) -> ScrollPosition {
+        let mut position = ScrollPosition::default();
+
+        self.list
+            .operate(&mut self.state.tree, list, renderer, &mut position);
+
+        position
+    }
+}
+
 impl<'a, 'b, Message, Theme, Renderer>
     iced::advanced::Overlay<Message, Theme, Renderer>
     for Overlay<'a, 'b, Message, Theme, Renderer>
@@ -302,27 +663,109 @@ where
             bounds.height - (self.position.y + self.target_height);
         let space_above = self.position.y;
 
+        let max_width = bounds.width - self.position.x;
+        let max_height = if space_below > space_above {
+            space_below
+        } else {
+            space_above
+        };
+
+        let field = self.search.as_ref().map(|search| {
+            let limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new(max_width, max_height),
+            )
+            .width(self.width);
+
+            search.field.as_widget().layout(
+                &mut self.state.search,
+                renderer,
+                &limits,
+            )
+        });
+
+        let field_height =
+            field.as_ref().map_or(0.0, |field| field.size().height);
+
         let limits = layout::Limits::new(
             Size::ZERO,
-            Size::new(
-                bounds.width - self.position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
-                },
-            ),
+            Size::new(max_width, (max_height - field_height).max(0.0)),
         )
         .width(self.width);
 
-        let node = self.list.layout(self.state, renderer, &limits);
-        let size = node.size();
+        let node = self.list.layout(&mut self.state.tree, renderer, &limits);
 
-        node.move_to(if space_below > space_above {
-            self.position + Vector::new(0.0, self.target_height)
-        } else {
-            self.position - Vector::new(0.0, size.height)
-        })
+        let option_height = self.option_height(renderer);
+
+        // Rows are only laid out one by one when they have views.
+        let content = node.children().first();
+        let rows = content.map_or(&[][..], layout::Node::children);
+
+        let row_height = match content {
+            Some(content) if !rows.is_empty() => {
+                content.size().height / rows.len() as f32
+            }
+            _ => option_height,
+        };
+
+        self.state.page_size =
+            ((node.size().height / row_height) as usize).max(1);
+
+        if let Some(option) = self.state.scroll_target.take() {
+            let headers =
+                self.sections.partition_point(|&start| start <= option);
+            let row = option + headers;
+
+            // The first option of a section brings its header along.
+            let top_row = if self.sections.binary_search(&option).is_ok() {
+                row - 1
+            } else {
+                row
+            };
+
+            let (top, bottom) = match (rows.get(top_row), rows.get(row)) {
+                (Some(top), Some(bottom)) => {
+                    (top.bounds().y, bottom.bounds().y + bottom.bounds().height)
+                }
+                _ => (
+                    top_row as f32 * option_height,
+                    (row + 1) as f32 * option_height,
+                ),
+            };
+
+            self.list.operate(
+                &mut self.state.tree,
+                Layout::new(&node),
+                renderer,
+                &mut ScrollIntoView { top, bottom },
+            );
+        }
+
+        let size =
+            Size::new(node.size().width, node.size().height + field_height);
+
+        let mut children = vec![node.move_to(Point::new(0.0, field_height))];
+
+        if let (Some(search), Some(field)) = (&self.search, field) {
+            if std::mem::take(&mut self.state.is_search_pending_focus) {
+                search.field.as_widget().operate(
+                    &mut self.state.search,
+                    Layout::new(&field),
+                    renderer,
+                    &mut Focus,
+                );
+            }
+
+            children.push(field);
+        }
+
+        layout::Node::with_children(size, children).move_to(
+            if space_below > space_above {
+                self.position + Vector::new(0.0, self.target_height)
+            } else {
+                self.position - Vector::new(0.0, size.height)
+            },
+        )
     }
 
     fn on_event(
@@ -334,12 +777,119 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let list = children.next().expect("Menu has a list");
 
-        self.list.on_event(
-            self.state, event, layout, cursor, renderer, clipboard, shell,
-            &bounds,
-        )
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if self.is_loading {
+                let since = *self.state.loading_since.get_or_insert(now);
+                let elapsed = now - since;
+
+                self.state.loading_elapsed = elapsed;
+
+                // The spinner only moves on to its next dot while it is
+                // scrolled into view.
+                if self.scroll_position(list, renderer).remaining
+                    < self.option_height(renderer)
+                {
+                    let step = LOADING_STEP.as_nanos();
+                    let left = step - elapsed.as_nanos() % step;
+
+                    shell.request_redraw(window::RedrawRequest::At(
+                        now + Duration::from_nanos(left as u64),
+                    ));
+                }
+            }
+        }
+
+        if let (Some(search), Some(field)) = (&mut self.search, children.next())
+        {
+            // Navigation keys go to the owner of the menu, not the field.
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Named(
+                        key::Named::Enter
+                        | key::Named::Escape
+                        | key::Named::PageUp
+                        | key::Named::PageDown,
+                    ),
+                ..
+            }) = event
+            {
+                return event::Status::Ignored;
+            }
+
+            let mut queries = Vec::new();
+            let mut field_shell = Shell::new(&mut queries);
+
+            let status = search.field.as_widget_mut().on_event(
+                &mut self.state.search,
+                event.clone(),
+                field,
+                cursor,
+                renderer,
+                clipboard,
+                &mut field_shell,
+                &layout.bounds(),
+            );
+
+            if let Some(request) = field_shell.redraw_request() {
+                shell.request_redraw(request);
+            }
+
+            if field_shell.is_layout_invalid() {
+                shell.invalidate_layout();
+            }
+
+            if field_shell.are_widgets_invalid() {
+                shell.invalidate_widgets();
+            }
+
+            if let Some(query) = queries.pop() {
+                *search.query = query;
+                shell.invalidate_layout();
+            }
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        let status = self.list.on_event(
+            &mut self.state.tree,
+            event,
+            list,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &list.bounds(),
+        );
+
+        if self.on_scroll_end.is_some() {
+            let position = self.scroll_position(list, renderer);
+            let is_scrolled_down = self
+                .state
+                .scroll_offset
+                .replace(position.offset)
+                .is_some_and(|offset| position.offset > offset);
+
+            // A list that cannot be scrolled shows its end from the start.
+            let is_short = position.offset <= 0.0;
+
+            if (is_scrolled_down || is_short)
+                && position.remaining <= 1.0
+                && !self.is_loading
+                && self.state.scrolled_to_end != Some(self.option_count)
+            {
+                if let Some(on_scroll_end) = self.on_scroll_end.take() {
+                    self.state.scrolled_to_end = Some(self.option_count);
+                    shell.publish(on_scroll_end);
+                }
+            }
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -349,8 +899,30 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.list
-            .mouse_interaction(self.state, layout, cursor, viewport, renderer)
+        let mut children = layout.children();
+        let list = children.next().expect("Menu has a list");
+
+        if let (Some(search), Some(field)) = (&self.search, children.next()) {
+            let interaction = search.field.as_widget().mouse_interaction(
+                &self.state.search,
+                field,
+                cursor,
+                viewport,
+                renderer,
+            );
+
+            if interaction != mouse::Interaction::default() {
+                return interaction;
+            }
+        }
+
+        self.list.mouse_interaction(
+            &self.state.tree,
+            list,
+            cursor,
+            viewport,
+            renderer,
+        )
     }
 
     fn draw(
@@ -374,22 +946,147 @@ where
             style.background,
         );
 
+        let mut children = layout.children();
+        let list = children.next().expect("Menu has a list");
+
         self.list.draw(
-            self.state, renderer, theme, defaults, layout, cursor, &bounds,
+            &self.state.tree,
+            renderer,
+            theme,
+            defaults,
+            list,
+            cursor,
+            &list.bounds(),
         );
+
+        if let (Some(search), Some(field)) = (&self.search, children.next()) {
+            search.field.as_widget().draw(
+                &self.state.search,
+                renderer,
+                theme,
+                defaults,
+                field,
+                cursor,
+                &bounds,
+            );
+        }
+    }
+}
+
+/// The search field of a [`Menu`] and the query it edits.
+struct Search<'a, Theme, Renderer> {
+    field: Element<'a, String, Theme, Renderer>,
+    query: &'a mut String,
+}
+
+/// Focuses every focusable widget it visits.
+struct Focus;
+
+impl Operation for Focus {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn operation::Focusable,
+        _id: Option<&Id>,
+    ) {
+        state.focus();
+    }
+}
+
+/// Scrolls the list of a [`Menu`] just enough to show the given vertical
+/// span of its contents.
+struct ScrollIntoView {
+    top: f32,
+    bottom: f32,
+}
+
+impl Operation for ScrollIntoView {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        let y = if self.top < translation.y {
+            self.top
+        } else if self.bottom > translation.y + bounds.height {
+            self.bottom - bounds.height
+        } else {
+            return;
+        };
+
+        state.scroll_to(AbsoluteOffset { x: 0.0, y });
+    }
+}
+
+/// Finds out how far the list of a [`Menu`] is scrolled.
+#[derive(Default)]
+struct ScrollPosition {
+    /// The vertical offset of the list.
+    offset: f32,
+    /// The height of the list left below the visible part.
+    remaining: f32,
+}
+
+impl Operation for ScrollPosition {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.offset = translation.y;
+        self.remaining =
+            content_bounds.height - (translation.y + bounds.height);
     }
 }
 
 struct List<'a, 'b, T, Message, Theme, Renderer>
 where
+    T: Clone,
     Theme: Catalog,
     Renderer: text::Renderer,
 {
-    options: &'a [T],
-    disabled: Option<Vec<bool>>,
+    options: Options<'a, T>,
+    disabled: Option<Cow<'a, [bool]>>,
+    disabled_reasons: Cow<'a, [Option<String>]>,
+    checked: Option<Vec<bool>>,
+    sections: Vec<(usize, String)>,
+    views: Vec<Element<'a, Message, Theme, Renderer>>,
+    option_view: Option<OptionView<'a, T, Message, Theme, Renderer>>,
+    loading: Option<Duration>,
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    on_close: Option<Message>,
     padding: Padding,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
@@ -398,6 +1095,49 @@ where
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
+/// The options of a [`Menu`], of which only some may be shown.
+struct Options<'a, T> {
+    all: &'a [T],
+    shown: Option<&'a [usize]>,
+}
+
+impl<'a, T> Options<'a, T> {
+    /// Returns the amount of shown options.
+    fn len(&self) -> usize {
+        self.shown.map_or(self.all.len(), <[usize]>::len)
+    }
+
+    /// Returns the shown option at the given index.
+    fn get(&self, index: usize) -> Option<&'a T> {
+        match self.shown {
+            Some(shown) => self.all.get(*shown.get(index)?),
+            None => self.all.get(index),
+        }
+    }
+
+    /// Returns an iterator over the shown options.
+    fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+}
+
+/// A function producing the [`Element`] shown for an option of a [`Menu`].
+type OptionView<'a, T, Message, Theme, Renderer> =
+    Box<dyn Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// A row of the list of a [`Menu`].
+enum Row<'a> {
+    /// The header of a section, with its label.
+    Header(&'a str),
+    /// The option at the given index.
+    Option(usize),
+    /// The spinner shown after the options while more are fetched.
+    Loading,
+}
+
+/// How long each dot of the loading spinner of a [`Menu`] stays lit.
+const LOADING_STEP: Duration = Duration::from_millis(300);
+
 impl<'a, 'b, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for List<'a, 'b, T, Message, Theme, Renderer>
 where
@@ -412,9 +1152,17 @@ where
         }
     }
 
+    fn children(&self) -> Vec<Tree> {
+        self.views.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.views);
+    }
+
     fn layout(
         &self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
@@ -424,18 +1172,67 @@ where
             self.text_size.unwrap_or_else(|| renderer.default_size());
 
         let text_line_height = self.text_line_height.to_absolute(text_size);
+        let option_height =
+            f32::from(text_line_height) + self.padding.vertical();
 
-        let size = {
-            let intrinsic = Size::new(
-                0.0,
-                (f32::from(text_line_height) + self.padding.vertical())
-                    * self.options.len() as f32,
-            );
+        if self.views.is_empty() {
+            let size = {
+                let intrinsic =
+                    Size::new(0.0, option_height * self.rows() as f32);
 
-            limits.resolve(Length::Fill, Length::Shrink, intrinsic)
-        };
+                limits.resolve(Length::Fill, Length::Shrink, intrinsic)
+            };
 
-        layout::Node::new(size)
+            return layout::Node::new(size);
+        }
+
+        let width = limits.max().width;
+        let inset = self.inset(text_size);
+        let view_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                (width - inset - self.padding.right).max(0.0),
+                f32::INFINITY,
+            ),
+        );
+
+        let mut rows = Vec::with_capacity(self.rows());
+        let mut y = 0.0;
+
+        for row in 0..self.rows() {
+            let view = match self.row(row) {
+                Some(Row::Option(index)) => {
+                    self.views.get(index).zip(tree.children.get_mut(index))
+                }
+                Some(Row::Header(_) | Row::Loading) | None => None,
+            };
+
+            let node = match view {
+                Some((view, tree)) => {
+                    let view =
+                        view.as_widget().layout(tree, renderer, &view_limits);
+                    let height = (view.size().height + self.padding.vertical())
+                        .max(option_height);
+                    let top = (height - view.size().height) / 2.0;
+
+                    layout::Node::with_children(
+                        Size::new(width, height),
+                        vec![view.move_to(Point::new(inset, top))],
+                    )
+                }
+                None => layout::Node::new(Size::new(width, option_height)),
+            };
+
+            let height = node.size().height;
+
+            rows.push(node.move_to(Point::new(0.0, y)));
+            y += height;
+        }
+
+        let size =
+            limits.resolve(Length::Fill, Length::Shrink, Size::new(0.0, y));
+
+        layout::Node::with_children(size, rows)
     }
 
     fn on_event(
@@ -455,19 +1252,15 @@ where
                     cursor.position_in(layout.bounds())
                 {
                     if let Some(clicked_index) =
-                        self.option_index_at(cursor_position, renderer)
+                        self.option_index_at(layout, cursor_position, renderer)
                     {
                         if !self.is_disabled(clicked_index) {
-                            if let Some(option) =
-                                self.options.get(clicked_index)
-                            {
-                                shell.publish((self.on_selected)(
-                                    option.clone(),
-                                ));
-                            }
+                            self.select(clicked_index, shell);
                         }
-                        return event::Status::Captured;
                     }
+
+                    // Clicks on section headers keep the menu open.
+                    return event::Status::Captured;
                 }
             }
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
@@ -475,7 +1268,7 @@ where
                     cursor.position_in(layout.bounds())
                 {
                     if let Some(new_hovered_option) =
-                        self.option_index_at(cursor_position, renderer)
+                        self.option_index_at(layout, cursor_position, renderer)
                     {
                         if !self.is_disabled(new_hovered_option) {
                             if let Some(on_option_hovered) =
@@ -504,20 +1297,15 @@ where
                     cursor.position_in(layout.bounds())
                 {
                     if let Some(new_hovered_option) =
-                        self.option_index_at(cursor_position, renderer)
+                        self.option_index_at(layout, cursor_position, renderer)
                     {
                         if !self.is_disabled(new_hovered_option) {
                             *self.hovered_option = Some(new_hovered_option);
-                            if let Some(option) =
-                                self.options.get(new_hovered_option)
-                            {
-                                shell.publish((self.on_selected)(
-                                    option.clone(),
-                                ));
-                            }
+                            self.select(new_hovered_option, shell);
                         }
-                        return event::Status::Captured;
                     }
+
+                    return event::Status::Captured;
                 }
             }
             _ => {}
@@ -536,7 +1324,7 @@ where
     ) -> mouse::Interaction {
         if let Some(cursor_position) = cursor.position_in(layout.bounds()) {
             if let Some(hovered_index) =
-                self.option_index_at(cursor_position, renderer)
+                self.option_index_at(layout, cursor_position, renderer)
             {
                 if !self.is_disabled(hovered_index) {
                     return mouse::Interaction::Pointer;
@@ -549,12 +1337,12 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         let style = Catalog::style(theme, self.class);
@@ -566,14 +1354,111 @@ where
             f32::from(self.text_line_height.to_absolute(text_size))
                 + self.padding.vertical();
 
-        let offset = viewport.y - bounds.y;
-        let start = (offset / option_height) as usize;
-        let end = ((offset + viewport.height) / option_height).ceil() as usize;
+        let rows: Vec<_> = if self.views.is_empty() {
+            let offset = viewport.y - bounds.y;
+            let start = (offset / option_height) as usize;
+            let end =
+                ((offset + viewport.height) / option_height).ceil() as usize;
+
+            (start..end.min(self.rows()))
+                .map(|row| {
+                    let bounds = Rectangle {
+                        x: bounds.x,
+                        y: bounds.y + (option_height * row as f32),
+                        width: bounds.width,
+                        height: option_height,
+                    };
+
+                    (row, bounds, None)
+                })
+                .collect()
+        } else {
+            layout
+                .children()
+                .enumerate()
+                .filter(|(_, row)| row.bounds().intersects(viewport))
+                .map(|(row, layout)| {
+                    (row, layout.bounds(), layout.children().next())
+                })
+                .collect()
+        };
+
+        for (row, bounds, view_layout) in rows {
+            let i = match self.row(row) {
+                Some(Row::Option(index)) => index,
+                Some(Row::Header(label)) => {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x + style.border.width,
+                                width: bounds.width - style.border.width * 2.0,
+                                ..bounds
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.header_background,
+                    );
+
+                    renderer.fill_text(
+                        Text {
+                            content: label.to_owned(),
+                            bounds: Size::new(f32::INFINITY, bounds.height),
+                            size: text_size,
+                            line_height: self.text_line_height,
+                            font: self
+                                .font
+                                .unwrap_or_else(|| renderer.default_font()),
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: self.text_shaping,
+                            wrapping: text::Wrapping::default(),
+                        },
+                        Point::new(
+                            bounds.x + self.padding.left,
+                            bounds.center_y(),
+                        ),
+                        style.header_text_color,
+                        *viewport,
+                    );
+
+                    continue;
+                }
+                Some(Row::Loading) => {
+                    let elapsed = self.loading.unwrap_or_default();
+                    let lit =
+                        (elapsed.as_millis() / LOADING_STEP.as_millis()) % 3;
+                    let size = text_size.0 / 3.0;
+
+                    for dot in 0..3 {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.center_x()
+                                        + (dot as f32 - 1.0) * size * 2.0
+                                        - size / 2.0,
+                                    y: bounds.center_y() - size / 2.0,
+                                    width: size,
+                                    height: size,
+                                },
+                                border: border::rounded(size / 2.0),
+                                ..renderer::Quad::default()
+                            },
+                            if dot == lit {
+                                style.text_color
+                            } else {
+                                style.text_color.scale_alpha(0.3)
+                            },
+                        );
+                    }
 
-        let visible_options = &self.options[start..end.min(self.options.len())];
+                    continue;
+                }
+                None => break,
+            };
 
-        for (i, option) in visible_options.iter().enumerate() {
-            let i = start + i;
+            let Some(option) = self.options.get(i) else {
+                break;
+            };
             let is_selected = *self.hovered_option == Some(i);
             let is_disabled = self
                 .disabled
@@ -582,13 +1467,6 @@ where
                 .copied()
                 .unwrap_or(false);
 
-            let bounds = Rectangle {
-                x: bounds.x,
-                y: bounds.y + (option_height * i as f32),
-                width: bounds.width,
-                height: option_height,
-            };
-
             if is_selected && !is_disabled {
                 renderer.fill_quad(
                     renderer::Quad {
@@ -617,28 +1495,170 @@ where
                 );
             }
 
-            renderer.fill_text(
-                Text {
-                    content: option.to_string(),
+            let text_color = if is_disabled {
+                style.disabled_text_color
+            } else if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            let reason = self
+                .disabled_reasons
+                .get(i)
+                .and_then(Option::as_ref)
+                .filter(|_| is_disabled && cursor.is_over(bounds));
+
+            // The reason takes up to half of the row, and the label is
+            // clipped before it.
+            let label_bounds = reason.map(|reason| {
+                let font = self.font.unwrap_or_else(|| renderer.default_font());
+                let right = bounds.x + bounds.width - self.padding.right;
+                let available =
+                    (bounds.width - self.padding.horizontal()).max(0.0) / 2.0;
+
+                let width = Renderer::Paragraph::with_text(Text {
+                    content: reason.as_str(),
                     bounds: Size::new(f32::INFINITY, bounds.height),
                     size: text_size,
                     line_height: self.text_line_height,
-                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    font,
                     horizontal_alignment: alignment::Horizontal::Left,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
-                    wrapping: text::Wrapping::default(),
-                },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
-                if is_disabled {
-                    style.disabled_text_color
-                } else if is_selected {
-                    style.selected_text_color
-                } else {
-                    style.text_color
-                },
-                *viewport,
-            );
+                    wrapping: text::Wrapping::None,
+                })
+                .min_width()
+                .min(available);
+
+                let reason_bounds = Rectangle {
+                    x: right - width,
+                    width,
+                    ..bounds
+                };
+
+                renderer.with_layer(reason_bounds, |renderer| {
+                    renderer.fill_text(
+                        Text {
+                            content: reason.clone(),
+                            bounds: Size::new(f32::INFINITY, bounds.height),
+                            size: text_size,
+                            line_height: self.text_line_height,
+                            font,
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: self.text_shaping,
+                            wrapping: text::Wrapping::None,
+                        },
+                        Point::new(reason_bounds.x, bounds.center_y()),
+                        text_color,
+                        *viewport,
+                    );
+                });
+
+                Rectangle {
+                    width: (reason_bounds.x - self.padding.right - bounds.x)
+                        .max(0.0),
+                    ..bounds
+                }
+            });
+
+            let text_x = bounds.x + self.padding.left;
+
+            if let Some(checked) = &self.checked {
+                if checked.get(i).copied().unwrap_or(false) {
+                    renderer.fill_text(
+                        Text {
+                            content: Renderer::CHECKMARK_ICON.to_string(),
+                            bounds: Size::new(text_size.0, bounds.height),
+                            size: text_size,
+                            line_height: self.text_line_height,
+                            font: Renderer::ICON_FONT,
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: text::Shaping::Basic,
+                            wrapping: text::Wrapping::default(),
+                        },
+                        Point::new(text_x, bounds.center_y()),
+                        text_color,
+                        *viewport,
+                    );
+                }
+            }
+
+            if let Some(label_bounds) = label_bounds {
+                renderer.start_layer(label_bounds);
+            }
+
+            if let (Some(view), Some(view_layout)) =
+                (self.views.get(i), view_layout)
+            {
+                view.as_widget().draw(
+                    &tree.children[i],
+                    renderer,
+                    theme,
+                    &renderer::Style { text_color },
+                    view_layout,
+                    cursor,
+                    viewport,
+                );
+            } else if let Some(option_view) = &self.option_view {
+                let inset = self.inset(text_size);
+                let view = option_view(option);
+                let mut tree = Tree::new(&view);
+
+                let node = view.as_widget().layout(
+                    &mut tree,
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(
+                            (bounds.width - inset - self.padding.right)
+                                .max(0.0),
+                            bounds.height,
+                        ),
+                    ),
+                );
+
+                let top = bounds.center_y() - node.size().height / 2.0;
+                let node = node.move_to(Point::new(bounds.x + inset, top));
+
+                view.as_widget().draw(
+                    &tree,
+                    renderer,
+                    theme,
+                    &renderer::Style { text_color },
+                    Layout::new(&node),
+                    cursor,
+                    viewport,
+                );
+            } else {
+                renderer.fill_text(
+                    Text {
+                        content: option.to_string(),
+                        bounds: Size::new(f32::INFINITY, bounds.height),
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font: self
+                            .font
+                            .unwrap_or_else(|| renderer.default_font()),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: self.text_shaping,
+                        wrapping: text::Wrapping::default(),
+                    },
+                    Point::new(
+                        bounds.x + self.inset(text_size),
+                        bounds.center_y(),
+                    ),
+                    text_color,
+                    *viewport,
+                );
+            }
+
+            if label_bounds.is_some() {
+                renderer.end_layer();
+            }
         }
     }
 }
@@ -675,6 +1695,10 @@ pub struct Style {
     pub disabled_text_color: Color,
     /// The background [`Color`] of a disabled option in the menu.
     pub disabled_background: Background,
+    /// The text [`Color`] of a section header in the menu.
+    pub header_text_color: Color,
+    /// The [`Background`] of a section header in the menu.
+    pub header_background: Background,
 }
 
 /// The theme catalog of a [`Menu`].
@@ -730,5 +1754,7 @@ pub fn default(theme: &Theme) -> Style {
             .color
             .scale_alpha(0.5)
             .into(),
+        header_text_color: palette.background.strong.color,
+        header_background: Color::TRANSPARENT.into(),
     }
 }
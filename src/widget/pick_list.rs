@@ -2,10 +2,8 @@
 //!
 //! # Example
 //! ```no_run
-//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
-//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
-//! #
-//! use iced::widget::pick_list;
+//! use iced::Element;
+//! use sweeten::widget::pick_list;
 //!
 //! struct State {
 //!    favorite: Option<Fruit>,
@@ -32,13 +30,12 @@
 //!         Fruit::Tomato,
 //!     ];
 //!
-//!     pick_list(
-//!         fruits,
-//!         state.favorite,
-//!         Message::FruitSelected,
-//!     )
-//!     .placeholder("Select your favorite fruit...")
-//!     .into()
+//!     pick_list(fruits, state.favorite, Message::FruitSelected)
+//!         .disabled_if(|fruit| {
+//!             matches!(fruit, Fruit::Tomato).then_some("Not really a fruit")
+//!         })
+//!         .placeholder("Select your favorite fruit...")
+//!         .into()
 //! }
 //!
 //! fn update(state: &mut State, message: Message) {
@@ -84,32 +81,34 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 use iced::advanced::text::{self, paragraph, Text};
+use iced::advanced::widget::operation::{Focusable, Operation};
 use iced::advanced::widget::tree::{self, Tree};
 use iced::advanced::{
     layout, mouse, overlay, renderer, Clipboard, Layout, Shell, Widget,
 };
 use iced::alignment;
 use iced::event::{self, Event};
-use iced::keyboard;
+use iced::keyboard::{self, key};
+use iced::time::{Duration, Instant};
 use iced::touch;
 use iced::{
     Background, Border, Color, Element, Length, Padding, Pixels, Point,
     Rectangle, Size, Theme, Vector,
 };
 
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 use std::f32;
 
+use crate::widget::operation::TabIndex;
 use crate::widget::overlay::menu::{self, Menu};
+use crate::widget::text_input::{self, TextInput};
 
 /// A widget for selecting a single value from a list of options.
 ///
 /// # Example
 /// ```no_run
-/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
-/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
-/// #
-/// use iced::widget::pick_list;
+/// use iced::Element;
+/// use sweeten::widget::pick_list;
 ///
 /// struct State {
 ///    favorite: Option<Fruit>,
@@ -136,13 +135,12 @@ use crate::widget::overlay::menu::{self, Menu};
 ///         Fruit::Tomato,
 ///     ];
 ///
-///     pick_list(
-///         fruits,
-///         state.favorite,
-///         Message::FruitSelected,
-///     )
-///     .placeholder("Select your favorite fruit...")
-///     .into()
+///     pick_list(fruits, state.favorite, Message::FruitSelected)
+///         .disabled_if(|fruit| {
+///             matches!(fruit, Fruit::Tomato).then_some("Not really a fruit")
+///         })
+///         .placeholder("Select your favorite fruit...")
+///         .into()
 /// }
 ///
 /// fn update(state: &mut State, message: Message) {
@@ -184,8 +182,20 @@ pub struct PickList<
     on_select: Box<dyn Fn(T) -> Message + 'a>,
     on_open: Option<Message>,
     on_close: Option<Message>,
+    on_clear: Option<Message>,
+    on_scroll_end: Option<Message>,
     options: L,
-    disabled: Option<Box<dyn Fn(&[T]) -> Vec<bool> + 'a>>,
+    disabled: Option<Vec<bool>>,
+    disabled_reasons: Vec<Option<String>>,
+    group_by: Option<Box<dyn Fn(&T) -> String + 'a>>,
+    option_view:
+        Option<Box<dyn Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a>>,
+    selected_view: Option<Element<'a, Message, Theme, Renderer>>,
+    is_searchable: bool,
+    search_filter: Option<Box<dyn Fn(&T, &str) -> bool + 'a>>,
+    search_placeholder: String,
+    virtualized: Option<WidthHint>,
+    is_loading: bool,
     placeholder: Option<String>,
     selected: Option<V>,
     width: Length,
@@ -195,6 +205,7 @@ pub struct PickList<
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
+    tab_index: i32,
     class: <Theme as Catalog>::Class<'a>,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
 }
@@ -213,16 +224,26 @@ where
     /// selected value, and the message to produce when an option is selected.
     pub fn new(
         options: L,
-        disabled: Option<impl Fn(&[T]) -> Vec<bool> + 'a>,
         selected: Option<V>,
         on_select: impl Fn(T) -> Message + 'a,
     ) -> Self {
         Self {
             on_select: Box::new(on_select),
-            disabled: disabled.map(|f| Box::new(f) as _),
+            disabled: None,
+            disabled_reasons: Vec::new(),
             on_open: None,
             on_close: None,
+            on_clear: None,
+            on_scroll_end: None,
             options,
+            group_by: None,
+            option_view: None,
+            selected_view: None,
+            is_searchable: false,
+            search_filter: None,
+            search_placeholder: String::from("Search..."),
+            virtualized: None,
+            is_loading: false,
             placeholder: None,
             selected,
             width: Length::Shrink,
@@ -232,17 +253,151 @@ where
             text_shaping: text::Shaping::default(),
             font: None,
             handle: Handle::default(),
+            tab_index: 0,
             class: <Theme as Catalog>::default(),
             menu_class: <Theme as Catalog>::default_menu(),
         }
     }
 
+    /// Creates a new [`PickList`] with the given list of options, an optional
+    /// function flagging the disabled ones, the current selected value, and
+    /// the message to produce when an option is selected.
+    #[deprecated(
+        note = "use `PickList::new` followed by `PickList::disabled` instead"
+    )]
+    pub fn with_disabled(
+        options: L,
+        disabled: Option<impl FnOnce(&[T]) -> Vec<bool>>,
+        selected: Option<V>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        let pick_list = Self::new(options, selected, on_select);
+
+        match disabled {
+            Some(disabled) => pick_list.disabled(disabled),
+            None => pick_list,
+        }
+    }
+
     /// Sets the placeholder of the [`PickList`].
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = Some(placeholder.into());
         self
     }
 
+    /// Disables the options of the [`PickList`] flagged by the given
+    /// function, which receives all the options at once.
+    pub fn disabled(
+        mut self,
+        disabled: impl FnOnce(&[T]) -> Vec<bool>,
+    ) -> Self {
+        self.disabled = Some(disabled(self.options.borrow()));
+        self.disabled_reasons.clear();
+        self
+    }
+
+    /// Disables the options of the [`PickList`] for which the given function
+    /// returns a reason.
+    ///
+    /// The function is called once for each option when the [`PickList`] is
+    /// built. Unless it is empty, the reason is shown next to a disabled
+    /// option while it is hovered in the menu.
+    pub fn disabled_if<R: Into<String>>(
+        mut self,
+        reason: impl Fn(&T) -> Option<R>,
+    ) -> Self {
+        let (disabled, reasons) = self
+            .options
+            .borrow()
+            .iter()
+            .map(|option| {
+                let reason = reason(option).map(Into::into);
+
+                (reason.is_some(), reason.filter(|reason| !reason.is_empty()))
+            })
+            .unzip();
+
+        self.disabled = Some(disabled);
+        self.disabled_reasons = reasons;
+        self
+    }
+
+    /// Groups the options of the [`PickList`] into sections under a header
+    /// with the label returned by the given function.
+    ///
+    /// A new section starts whenever the label changes from one option to
+    /// the next, so options of the same group should be kept together.
+    pub fn group_by(mut self, group: impl Fn(&T) -> String + 'a) -> Self {
+        self.group_by = Some(Box::new(group));
+        self
+    }
+
+    /// Sets the function that produces the [`Element`] shown for each option
+    /// in the menu of the [`PickList`], in place of its label.
+    ///
+    /// The element is drawn with the text color of the hovered, selected or
+    /// disabled option, but does not receive events; clicking it selects
+    /// the option.
+    pub fn option_view(
+        mut self,
+        view: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        self.option_view = Some(Box::new(view));
+        self
+    }
+
+    /// Sets the function that produces the [`Element`] shown for the
+    /// selected option in the closed [`PickList`], in place of its label.
+    pub fn selected_view(
+        mut self,
+        view: impl Fn(&T) -> Element<'a, Message, Theme, Renderer>,
+    ) -> Self {
+        self.selected_view = self
+            .selected
+            .as_ref()
+            .map(|selected| view(selected.borrow()));
+        self
+    }
+
+    /// Makes the [`PickList`] searchable, placing a search field at the top
+    /// of its menu that filters the options as the user types.
+    ///
+    /// By default, an option matches when its label contains the query,
+    /// ignoring case.
+    pub fn searchable(mut self, is_searchable: bool) -> Self {
+        self.is_searchable = is_searchable;
+        self
+    }
+
+    /// Sets the function used to match the options of a searchable
+    /// [`PickList`] against the query.
+    pub fn search_filter(
+        mut self,
+        filter: impl Fn(&T, &str) -> bool + 'a,
+    ) -> Self {
+        self.search_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the placeholder of the search field of a searchable [`PickList`].
+    pub fn search_placeholder(
+        mut self,
+        placeholder: impl Into<String>,
+    ) -> Self {
+        self.search_placeholder = placeholder.into();
+        self
+    }
+
+    /// Virtualizes the [`PickList`] for very large sets of options.
+    ///
+    /// A virtualized [`PickList`] estimates the width of its options with the
+    /// given [`WidthHint`] instead of measuring every one of them, and its
+    /// menu only lays out the rows in view, each one line of text tall.
+    pub fn virtualized(mut self, hint: WidthHint) -> Self {
+        self.virtualized = Some(hint);
+        self
+    }
+
     /// Sets the width of the [`PickList`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -288,6 +443,28 @@ where
         self
     }
 
+    /// Sets whether more options of the [`PickList`] are being fetched.
+    ///
+    /// The menu of a loading [`PickList`] shows a spinner after the options
+    /// fetched so far, which can still be hovered and selected.
+    pub fn loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
+    /// Sets the message that will be produced when the menu of the
+    /// [`PickList`] is scrolled to the end of its options.
+    ///
+    /// Together with [`on_open`](Self::on_open) and
+    /// [`loading`](Self::loading), it can be used to fetch the options page
+    /// by page. The message is produced once for every amount of options, and
+    /// not while loading, as soon as the menu is scrolled down to its end or
+    /// right away if the options do not fill it.
+    pub fn on_scroll_end(mut self, on_scroll_end: Message) -> Self {
+        self.on_scroll_end = Some(on_scroll_end);
+        self
+    }
+
     /// Sets the message that will be produced when the [`PickList`] is opened.
     pub fn on_open(mut self, on_open: Message) -> Self {
         self.on_open = Some(on_open);
@@ -300,6 +477,30 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the selection of the
+    /// [`PickList`] is cleared.
+    ///
+    /// A clear button is shown next to the [`Handle`] while a value is
+    /// selected, which can also be pressed with Delete or Backspace while the
+    /// [`PickList`] is focused and its menu is closed.
+    pub fn on_clear(mut self, on_clear: Message) -> Self {
+        self.on_clear = Some(on_clear);
+        self
+    }
+
+    /// Sets the position of the [`PickList`] in the keyboard navigation
+    /// order of [`focus_next`] and [`focus_previous`].
+    ///
+    /// Negative values remove the [`PickList`] from the navigation order,
+    /// but it can still be focused by a click. See [`TabIndex`] for details.
+    ///
+    /// [`focus_next`]: crate::widget::operation::focus_next
+    /// [`focus_previous`]: crate::widget::operation::focus_previous
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Sets the style of the [`PickList`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -342,6 +543,101 @@ where
         self.menu_class = class.into();
         self
     }
+
+    /// Returns the indices of the options shown for the given search query.
+    fn visible_options(&self, query: &str) -> Vec<usize> {
+        let options = self.options.borrow();
+
+        if !self.is_searchable || query.is_empty() {
+            return (0..options.len()).collect();
+        }
+
+        let lowercase = query.to_lowercase();
+
+        options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| match &self.search_filter {
+                Some(filter) => filter(option, query),
+                None => option.to_string().to_lowercase().contains(&lowercase),
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Returns the bounds of the clear button, if it is shown.
+    fn clear_bounds(
+        &self,
+        bounds: Rectangle,
+        text_size: Pixels,
+    ) -> Option<Rectangle> {
+        if self.on_clear.is_none() || self.selected.is_none() {
+            return None;
+        }
+
+        Some(Rectangle {
+            x: bounds.x + bounds.width
+                - self.padding.right
+                - text_size.0 * 2.0
+                - self.padding.left,
+            width: text_size.0,
+            ..bounds
+        })
+    }
+
+    /// Returns whether the option at the given index is disabled.
+    fn is_disabled(&self, index: usize) -> bool {
+        self.disabled
+            .as_ref()
+            .and_then(|disabled| disabled.get(index))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Returns the options shown for the given search query, keeping them
+    /// in the `cache` until the query changes or the [`PickList`] is rebuilt.
+    fn filtered<'s>(
+        &self,
+        cache: &'s mut Option<Filtered>,
+        query: &str,
+    ) -> &'s Filtered {
+        if cache
+            .as_ref()
+            .is_some_and(|filtered| filtered.query != query)
+        {
+            *cache = None;
+        }
+
+        cache.get_or_insert_with(|| {
+            let options = self.options.borrow();
+            let visible = self.visible_options(query);
+            let is_filtered = visible.len() != options.len();
+
+            Filtered {
+                query: query.to_owned(),
+                disabled: visible
+                    .iter()
+                    .map(|&index| self.is_disabled(index))
+                    .collect(),
+                disabled_reasons: if is_filtered
+                    && !self.disabled_reasons.is_empty()
+                {
+                    visible
+                        .iter()
+                        .map(|&index| self.disabled_reasons[index].clone())
+                        .collect()
+                } else {
+                    Vec::new()
+                },
+                sections: self
+                    .group_by
+                    .as_ref()
+                    .map(|group_by| sections(&visible, options, group_by))
+                    .unwrap_or_default(),
+                visible,
+            }
+        })
+    }
 }
 
 impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -362,6 +658,32 @@ where
         tree::State::new(State::<Renderer::Paragraph>::new())
     }
 
+    fn children(&self) -> Vec<Tree> {
+        self.selected_view.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        // The options may have changed since the last view.
+        state.filtered = None;
+
+        tree.diff_children(self.selected_view.as_slice());
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.custom(&mut TabIndex(self.tab_index), None);
+        operation.focusable(state, None);
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -382,8 +704,6 @@ where
             self.text_size.unwrap_or_else(|| renderer.default_size());
         let options = self.options.borrow();
 
-        state.options.resize_with(options.len(), Default::default);
-
         let option_text = Text {
             content: "",
             bounds: Size::new(
@@ -399,16 +719,6 @@ where
             wrapping: text::Wrapping::default(),
         };
 
-        for (option, paragraph) in options.iter().zip(state.options.iter_mut())
-        {
-            let label = option.to_string();
-
-            paragraph.update(Text {
-                content: &label,
-                ..option_text
-            });
-        }
-
         if let Some(placeholder) = &self.placeholder {
             state.placeholder.update(Text {
                 content: placeholder,
@@ -418,10 +728,37 @@ where
 
         let max_width = match self.width {
             Length::Shrink => {
-                let labels_width =
+                let labels_width = if let Some(WidthHint::Fixed(width)) =
+                    self.virtualized
+                {
+                    width
+                } else {
+                    let step = match self.virtualized {
+                        Some(WidthHint::Sample(amount)) => {
+                            options.len().div_ceil(amount.max(1)).max(1)
+                        }
+                        _ => 1,
+                    };
+
+                    let sample = options.iter().step_by(step);
+
+                    state.options.resize_with(sample.len(), Default::default);
+
+                    for (option, paragraph) in
+                        sample.zip(state.options.iter_mut())
+                    {
+                        let label = option.to_string();
+
+                        paragraph.update(Text {
+                            content: &label,
+                            ..option_text
+                        });
+                    }
+
                     state.options.iter().fold(0.0, |width, paragraph| {
                         f32::max(width, paragraph.min_width())
-                    });
+                    })
+                };
 
                 labels_width.max(
                     self.placeholder
@@ -433,10 +770,30 @@ where
             _ => 0.0,
         };
 
+        let mut handle_width = text_size.0 + self.padding.left;
+
+        // Room for the clear button is kept while nothing is selected, too.
+        if self.on_clear.is_some() {
+            handle_width += text_size.0 + self.padding.left;
+        }
+
+        let view = self.selected_view.as_ref().map(|view| {
+            let limits = limits
+                .width(self.width)
+                .shrink(self.padding)
+                .shrink(Size::new(handle_width, 0.0));
+
+            view.as_widget()
+                .layout(&mut tree.children[0], renderer, &limits)
+        });
+
         let size = {
+            let view_size =
+                view.as_ref().map_or(Size::ZERO, layout::Node::size);
             let intrinsic = Size::new(
-                max_width + text_size.0 + self.padding.left,
-                f32::from(self.text_line_height.to_absolute(text_size)),
+                max_width.max(view_size.width) + handle_width,
+                f32::from(self.text_line_height.to_absolute(text_size))
+                    .max(view_size.height),
             );
 
             limits
@@ -446,7 +803,17 @@ where
                 .expand(self.padding)
         };
 
-        layout::Node::new(size)
+        match view {
+            Some(view) => {
+                let top = (size.height - view.size().height) / 2.0;
+
+                layout::Node::with_children(
+                    size,
+                    vec![view.move_to(Point::new(self.padding.left, top))],
+                )
+            }
+            None => layout::Node::new(size),
+        }
     }
 
     fn on_event(
@@ -455,7 +822,7 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
@@ -465,15 +832,35 @@ where
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let state =
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+                let text_size =
+                    self.text_size.unwrap_or_else(|| renderer.default_size());
+
+                if let Some(on_clear) = self.on_clear.as_ref().filter(|_| {
+                    self.clear_bounds(layout.bounds(), text_size)
+                        .is_some_and(|bounds| cursor.is_over(bounds))
+                }) {
+                    state.is_focused = true;
+
+                    if state.is_open {
+                        state.is_open = false;
+
+                        if let Some(on_close) = &self.on_close {
+                            shell.publish(on_close.clone());
+                        }
+                    }
+
+                    shell.publish(on_clear.clone());
+
+                    return event::Status::Captured;
+                }
 
                 if state.is_open {
                     if let Some(hovered) = state.hovered_option {
-                        let options = self.options.borrow();
-                        if let Some(disabled_fn) = &self.disabled {
-                            let disabled = disabled_fn(options);
-                            if hovered < disabled.len() && disabled[hovered] {
-                                return event::Status::Captured;
-                            }
+                        let filtered =
+                            self.filtered(&mut state.filtered, &state.query);
+
+                        if filtered.disabled.get(hovered) == Some(&true) {
+                            return event::Status::Captured;
                         }
                     }
 
@@ -481,6 +868,7 @@ where
                     // disabled, so cursor was clicked either outside its bounds
                     // or on an enabled option, either way we close the overlay.
                     state.is_open = false;
+                    state.is_focused = cursor.is_over(layout.bounds());
 
                     if let Some(on_close) = &self.on_close {
                         shell.publish(on_close.clone());
@@ -491,18 +879,31 @@ where
                     let selected = self.selected.as_ref().map(Borrow::borrow);
 
                     state.is_open = true;
+                    state.is_focused = true;
+                    state.query.clear();
+                    state.last_query.clear();
                     state.hovered_option = self
                         .options
                         .borrow()
                         .iter()
                         .position(|option| Some(option) == selected);
 
+                    if let Some(index) = state.hovered_option {
+                        state.menu.scroll_to(index);
+                    }
+
+                    if self.is_searchable {
+                        state.menu.focus_search();
+                    }
+
                     if let Some(on_open) = &self.on_open {
                         shell.publish(on_open.clone());
                     }
 
                     event::Status::Captured
                 } else {
+                    state.unfocus();
+
                     event::Status::Ignored
                 }
             }
@@ -527,11 +928,6 @@ where
 
                     let options = self.options.borrow();
                     let selected = self.selected.as_ref().map(Borrow::borrow);
-                    let disabled = self
-                        .disabled
-                        .as_ref()
-                        .map(|f| f(options))
-                        .unwrap_or_else(|| vec![false; options.len()]);
 
                     let next_option = if y < 0.0 {
                         if let Some(selected) = selected {
@@ -542,7 +938,7 @@ where
                                 if let Some(pos) =
                                     options.iter().position(|opt| opt == option)
                                 {
-                                    if !disabled[pos] {
+                                    if !self.is_disabled(pos) {
                                         break;
                                     }
                                 }
@@ -553,7 +949,7 @@ where
                             options
                                 .iter()
                                 .enumerate()
-                                .find(|(i, _)| !disabled[*i])
+                                .find(|(i, _)| !self.is_disabled(*i))
                                 .map(|(_, opt)| opt)
                         }
                     } else if y > 0.0 {
@@ -566,7 +962,7 @@ where
                                 if let Some(pos) =
                                     options.iter().position(|opt| opt == option)
                                 {
-                                    if !disabled[pos] {
+                                    if !self.is_disabled(pos) {
                                         break;
                                     }
                                 }
@@ -578,7 +974,7 @@ where
                                 .iter()
                                 .enumerate()
                                 .rev()
-                                .find(|(i, _)| !disabled[*i])
+                                .find(|(i, _)| !self.is_disabled(*i))
                                 .map(|(_, opt)| opt)
                         }
                     } else {
@@ -594,6 +990,137 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if key != key::Named::Space => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if let (
+                    key::Named::Delete | key::Named::Backspace,
+                    Some(on_clear),
+                    Some(_),
+                ) = (key, &self.on_clear, &self.selected)
+                {
+                    // While the menu is open, these keys edit the search
+                    // query instead.
+                    if state.is_focused && !state.is_open {
+                        shell.publish(on_clear.clone());
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if !state.is_open {
+                    if key == key::Named::Escape && state.is_focused {
+                        state.unfocus();
+
+                        return event::Status::Captured;
+                    }
+
+                    return event::Status::Ignored;
+                }
+
+                let options = self.options.borrow();
+                let Filtered {
+                    visible, disabled, ..
+                } = self.filtered(&mut state.filtered, &state.query);
+
+                let is_enabled =
+                    |index: &usize| disabled.get(*index) == Some(&false);
+                let hovered = state.hovered_option;
+
+                match key {
+                    key::Named::Enter => {
+                        if let Some(option) = hovered
+                            .filter(is_enabled)
+                            .and_then(|index| visible.get(index))
+                            .map(|&index| &options[index])
+                        {
+                            state.is_open = false;
+
+                            shell.publish((self.on_select)(option.clone()));
+
+                            if let Some(on_close) = &self.on_close {
+                                shell.publish(on_close.clone());
+                            }
+                        }
+
+                        event::Status::Captured
+                    }
+                    key::Named::Escape => {
+                        state.is_open = false;
+                        state.unfocus();
+
+                        if let Some(on_close) = &self.on_close {
+                            shell.publish(on_close.clone());
+                        }
+
+                        event::Status::Captured
+                    }
+                    key => {
+                        if state.menu.navigate(
+                            key,
+                            &mut state.hovered_option,
+                            disabled.len(),
+                            |index| disabled[index],
+                        ) {
+                            event::Status::Captured
+                        } else {
+                            event::Status::Ignored
+                        }
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                text: Some(text),
+                modifiers,
+                ..
+            }) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if !(state.is_open || state.is_focused)
+                    || modifiers.command()
+                    || modifiers.alt()
+                    || text.chars().any(char::is_control)
+                {
+                    return event::Status::Ignored;
+                }
+
+                let options = self.options.borrow();
+                let query = if state.is_open { &state.query } else { "" };
+                let Filtered {
+                    visible, disabled, ..
+                } = self.filtered(&mut state.filtered, query);
+                let label = |index: usize| options[visible[index]].to_string();
+
+                let current = if state.is_open {
+                    state.hovered_option
+                } else {
+                    let selected = self.selected.as_ref().map(Borrow::borrow);
+
+                    options.iter().position(|option| Some(option) == selected)
+                };
+
+                let prefix = state.type_ahead.push(&text, Instant::now());
+
+                if let Some(index) =
+                    find_by_prefix(label, disabled, current, prefix)
+                {
+                    if state.is_open {
+                        state.hovered_option = Some(index);
+                        state.menu.scroll_to(index);
+                    } else if current != Some(index) {
+                        shell.publish((self.on_select)(
+                            options[visible[index]].clone(),
+                        ));
+                    }
+                }
+
+                event::Status::Captured
+            }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 let state =
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
@@ -638,11 +1165,6 @@ where
         let selected = self.selected.as_ref().map(Borrow::borrow);
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let options = self.options.borrow();
-        let disabled_options = self
-            .disabled
-            .as_ref()
-            .map(|f| f(options))
-            .unwrap_or_else(|| vec![false; options.len()]);
 
         let bounds = layout.bounds();
         let is_mouse_over = cursor.is_over(bounds);
@@ -650,6 +1172,8 @@ where
 
         let status = if state.is_open {
             Status::Opened
+        } else if state.is_focused {
+            Status::Focused
         } else if is_mouse_over {
             Status::Hovered
         } else {
@@ -667,66 +1191,39 @@ where
             style.background,
         );
 
-        let handle = match &self.handle {
-            Handle::Arrow { size } => Some((
-                Renderer::ICON_FONT,
-                Renderer::ARROW_DOWN_ICON,
-                *size,
-                text::LineHeight::default(),
-                text::Shaping::Basic,
-            )),
-            Handle::Static(Icon {
-                font,
-                code_point,
-                size,
-                line_height,
-                shaping,
-            }) => Some((*font, *code_point, *size, *line_height, *shaping)),
-            Handle::Dynamic { open, closed } => {
-                if state.is_open {
-                    Some((
-                        open.font,
-                        open.code_point,
-                        open.size,
-                        open.line_height,
-                        open.shaping,
-                    ))
-                } else {
-                    Some((
-                        closed.font,
-                        closed.code_point,
-                        closed.size,
-                        closed.line_height,
-                        closed.shaping,
-                    ))
-                }
-            }
-            Handle::None => None,
-        };
+        draw_handle(
+            renderer,
+            &self.handle,
+            state.is_open,
+            bounds,
+            self.padding,
+            style.handle_color,
+            viewport,
+        );
 
-        if let Some((font, code_point, size, line_height, shaping)) = handle {
-            let size = size.unwrap_or_else(|| renderer.default_size());
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let clear_bounds = self.clear_bounds(bounds, text_size);
 
+        if let Some(clear_bounds) = clear_bounds {
             renderer.fill_text(
                 Text {
-                    content: code_point.to_string(),
-                    size,
-                    line_height,
+                    content: String::from("×"),
+                    size: text_size,
+                    line_height: self.text_line_height,
                     font,
-                    bounds: Size::new(
-                        bounds.width,
-                        f32::from(line_height.to_absolute(size)),
-                    ),
-                    horizontal_alignment: alignment::Horizontal::Right,
+                    bounds: clear_bounds.size(),
+                    horizontal_alignment: alignment::Horizontal::Center,
                     vertical_alignment: alignment::Vertical::Center,
-                    shaping,
+                    shaping: text::Shaping::Basic,
                     wrapping: text::Wrapping::default(),
                 },
-                Point::new(
-                    bounds.x + bounds.width - self.padding.right,
-                    bounds.center_y(),
-                ),
-                style.handle_color,
+                clear_bounds.center(),
+                if cursor.is_over(clear_bounds) {
+                    style.clear_hovered_color
+                } else {
+                    style.clear_color
+                },
                 *viewport,
             );
         }
@@ -734,16 +1231,13 @@ where
         let label = selected.map(ToString::to_string);
 
         if let Some(label) = label.or_else(|| self.placeholder.clone()) {
-            let text_size =
-                self.text_size.unwrap_or_else(|| renderer.default_size());
-
             // Get the index of the selected item to check if it's disabled
             let selected_index = selected.and_then(|selected| {
                 options.iter().position(|option| option == selected)
             });
 
             let text_color = if is_selected {
-                if selected_index.map_or(false, |i| disabled_options[i]) {
+                if selected_index.is_some_and(|i| self.is_disabled(i)) {
                     style.disabled_text_color
                 } else {
                     style.text_color
@@ -752,6 +1246,22 @@ where
                 style.placeholder_color
             };
 
+            if let (Some(view), Some(view_layout)) =
+                (&self.selected_view, layout.children().next())
+            {
+                view.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    &renderer::Style { text_color },
+                    view_layout,
+                    cursor,
+                    viewport,
+                );
+
+                return;
+            }
+
             renderer.fill_text(
                 Text {
                     content: label,
@@ -759,7 +1269,10 @@ where
                     line_height: self.text_line_height,
                     font,
                     bounds: Size::new(
-                        bounds.width - self.padding.horizontal(),
+                        clear_bounds
+                            .map_or(bounds.x + bounds.width, |clear| clear.x)
+                            - bounds.x
+                            - self.padding.horizontal(),
                         f32::from(self.text_line_height.to_absolute(text_size)),
                     ),
                     horizontal_alignment: alignment::Horizontal::Left,
@@ -787,7 +1300,29 @@ where
         if state.is_open {
             let bounds = layout.bounds();
             let options = self.options.borrow();
-            let disabled = self.disabled.as_ref().map(|f| f(options));
+            let Filtered {
+                visible,
+                disabled,
+                disabled_reasons,
+                sections,
+                ..
+            } = self.filtered(&mut state.filtered, &state.query);
+
+            if state.query != state.last_query {
+                state.last_query.clone_from(&state.query);
+                state.hovered_option =
+                    disabled.iter().position(|&disabled| !disabled);
+                state.menu.scroll_to(state.hovered_option.unwrap_or(0));
+            }
+
+            // Options may come and go while the menu is open, so the hovered
+            // one is only forgotten once it is gone.
+            if state
+                .hovered_option
+                .is_some_and(|index| index >= visible.len())
+            {
+                state.hovered_option = None;
+            }
 
             let on_select = &self.on_select;
 
@@ -799,19 +1334,66 @@ where
                     state.is_open = false;
                     (on_select)(option)
                 },
-                disabled,
+                self.disabled
+                    .is_some()
+                    .then_some(Cow::Borrowed(disabled.as_slice())),
                 None,
                 &self.menu_class,
             )
             .width(bounds.width)
             .padding(self.padding)
             .font(font)
-            .text_shaping(self.text_shaping);
+            .text_shaping(self.text_shaping)
+            .virtualized(self.virtualized.is_some())
+            .loading(self.is_loading);
+
+            if visible.len() != options.len() {
+                menu = menu.shown(visible);
+            }
+
+            if let Some(on_scroll_end) = &self.on_scroll_end {
+                menu = menu.on_scroll_end(on_scroll_end.clone());
+            }
+
+            if let Some(on_close) = &self.on_close {
+                menu = menu.on_close(on_close.clone());
+            }
 
             if let Some(text_size) = self.text_size {
                 menu = menu.text_size(text_size);
             }
 
+            if !self.disabled_reasons.is_empty() {
+                menu =
+                    menu.disabled_reasons(if visible.len() == options.len() {
+                        self.disabled_reasons.as_slice()
+                    } else {
+                        disabled_reasons.as_slice()
+                    });
+            }
+
+            if let Some(option_view) = &self.option_view {
+                menu = menu.option_view(move |option| option_view(option));
+            }
+
+            if !sections.is_empty() {
+                menu = menu.sections(sections.clone());
+            }
+
+            if self.is_searchable {
+                let mut field =
+                    TextInput::new(&self.search_placeholder, &state.query)
+                        .on_input(|query| query)
+                        .padding(self.padding)
+                        .font(font);
+
+                if let Some(text_size) = self.text_size {
+                    field = field.size(text_size);
+                }
+
+                menu = menu.search(field, &mut state.query);
+            }
+
             Some(menu.overlay(layout.position() + translation, bounds.height))
         } else {
             None
@@ -842,7 +1424,12 @@ struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
+    type_ahead: TypeAhead,
+    query: String,
+    last_query: String,
+    filtered: Option<Filtered>,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
 }
@@ -854,19 +1441,217 @@ impl<P: text::Paragraph> State<P> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_focused: bool::default(),
             hovered_option: Option::default(),
+            type_ahead: TypeAhead::default(),
+            query: String::new(),
+            last_query: String::new(),
+            filtered: None,
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
         }
     }
 }
 
+impl<P: text::Paragraph> Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.type_ahead = TypeAhead::default();
+    }
+}
+
 impl<P: text::Paragraph> Default for State<P> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// The options of a [`PickList`] shown for a search query.
+#[derive(Debug)]
+struct Filtered {
+    query: String,
+    /// The indices of the shown options.
+    visible: Vec<usize>,
+    /// Whether each of the shown options is disabled.
+    disabled: Vec<bool>,
+    /// The reasons of the shown options, unless every option is shown.
+    disabled_reasons: Vec<Option<String>>,
+    /// The index of the first shown option of each group, with its label.
+    sections: Vec<(usize, String)>,
+}
+
+/// Groups the `visible` options into sections, each given by the index of
+/// its first option and its label.
+fn sections<T>(
+    visible: &[usize],
+    options: &[T],
+    group_by: impl Fn(&T) -> String,
+) -> Vec<(usize, String)> {
+    let mut sections: Vec<(usize, String)> = Vec::new();
+
+    for (index, &option) in visible.iter().enumerate() {
+        let label = group_by(&options[option]);
+
+        if sections.last().map(|(_, last)| last) != Some(&label) {
+            sections.push((index, label));
+        }
+    }
+
+    sections
+}
+
+/// How long a pause in typing may last before type-ahead starts over.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The prefix typed to jump to an option of a pick list.
+#[derive(Debug, Default)]
+pub(crate) struct TypeAhead {
+    prefix: String,
+    last_typed: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// Appends the typed text to the prefix, starting over if the last key
+    /// was typed too long ago, and returns the lowercase prefix.
+    pub(crate) fn push(&mut self, text: &str, now: Instant) -> &str {
+        if self
+            .last_typed
+            .is_none_or(|last_typed| now - last_typed > TYPE_AHEAD_TIMEOUT)
+        {
+            self.prefix.clear();
+        }
+
+        self.prefix.push_str(&text.to_lowercase());
+        self.last_typed = Some(now);
+
+        &self.prefix
+    }
+}
+
+/// Finds the first enabled option after `current` whose label starts with
+/// the given lowercase `prefix`, wrapping around the list.
+///
+/// The current option is kept while it still matches a growing prefix.
+/// Typing the same letter over and over cycles through the options that
+/// start with it.
+pub(crate) fn find_by_prefix(
+    label: impl Fn(usize) -> String,
+    disabled: &[bool],
+    current: Option<usize>,
+    prefix: &str,
+) -> Option<usize> {
+    let mut chars = prefix.chars();
+    let first = chars.next()?;
+
+    let (prefix, skip) = if chars.all(|c| c == first) {
+        (&prefix[..first.len_utf8()], 1)
+    } else {
+        (prefix, 0)
+    };
+
+    let start = current.map_or(0, |index| index + skip);
+
+    (0..disabled.len())
+        .map(|i| (start + i) % disabled.len())
+        .find(|&index| {
+            !disabled[index] && label(index).to_lowercase().starts_with(prefix)
+        })
+}
+
+/// Draws the [`Handle`] of a pick list at the right side of its `bounds`.
+pub(crate) fn draw_handle<Renderer>(
+    renderer: &mut Renderer,
+    handle: &Handle<Renderer::Font>,
+    is_open: bool,
+    bounds: Rectangle,
+    padding: Padding,
+    color: Color,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let handle = match handle {
+        Handle::Arrow { size } => Some((
+            Renderer::ICON_FONT,
+            Renderer::ARROW_DOWN_ICON,
+            *size,
+            text::LineHeight::default(),
+            text::Shaping::Basic,
+        )),
+        Handle::Static(Icon {
+            font,
+            code_point,
+            size,
+            line_height,
+            shaping,
+        }) => Some((*font, *code_point, *size, *line_height, *shaping)),
+        Handle::Dynamic { open, closed } => {
+            if is_open {
+                Some((
+                    open.font,
+                    open.code_point,
+                    open.size,
+                    open.line_height,
+                    open.shaping,
+                ))
+            } else {
+                Some((
+                    closed.font,
+                    closed.code_point,
+                    closed.size,
+                    closed.line_height,
+                    closed.shaping,
+                ))
+            }
+        }
+        Handle::None => None,
+    };
+
+    if let Some((font, code_point, size, line_height, shaping)) = handle {
+        let size = size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_text(
+            Text {
+                content: code_point.to_string(),
+                size,
+                line_height,
+                font,
+                bounds: Size::new(
+                    bounds.width,
+                    f32::from(line_height.to_absolute(size)),
+                ),
+                horizontal_alignment: alignment::Horizontal::Right,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping,
+                wrapping: text::Wrapping::default(),
+            },
+            Point::new(
+                bounds.x + bounds.width - padding.right,
+                bounds.center_y(),
+            ),
+            color,
+            *viewport,
+        );
+    }
+}
+
+/// How a virtualized [`PickList`] estimates the width of its options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WidthHint {
+    /// Measures the given amount of options, evenly spread among them.
+    Sample(usize),
+    /// Assumes the widest option takes the given amount of pixels.
+    Fixed(f32),
+}
+
 /// The handle to the right side of the [`PickList`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Handle<Font> {
@@ -920,6 +1705,8 @@ pub enum Status {
     Hovered,
     /// The [`PickList`] is open.
     Opened,
+    /// The [`PickList`] is focused, so it takes keyboard input while closed.
+    Focused,
 }
 
 /// The appearance of a pick list.
@@ -933,6 +1720,10 @@ pub struct Style {
     pub placeholder_color: Color,
     /// The handle [`Color`] of the pick list.
     pub handle_color: Color,
+    /// The [`Color`] of the clear button of the pick list.
+    pub clear_color: Color,
+    /// The [`Color`] of the clear button of the pick list when hovered.
+    pub clear_hovered_color: Color,
     /// The [`Background`] of the pick list.
     pub background: Background,
     /// The [`Border`] of the pick list.
@@ -940,7 +1731,7 @@ pub struct Style {
 }
 
 /// The theme catalog of a [`PickList`].
-pub trait Catalog: menu::Catalog {
+pub trait Catalog: text_input::Catalog + menu::Catalog {
     /// The item class of the [`Catalog`].
     type Class<'a>;
 
@@ -987,6 +1778,8 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         background: palette.background.weak.color.into(),
         placeholder_color: palette.background.strong.color,
         handle_color: palette.background.weak.text,
+        clear_color: palette.background.strong.color,
+        clear_hovered_color: palette.primary.strong.color,
         border: Border {
             radius: 2.0.into(),
             width: 1.0,
@@ -996,7 +1789,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
 
     match status {
         Status::Active => active,
-        Status::Hovered | Status::Opened => Style {
+        Status::Hovered | Status::Opened | Status::Focused => Style {
             border: Border {
                 color: palette.primary.strong.color,
                 ..active.border
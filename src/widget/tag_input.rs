@@ -0,0 +1,990 @@
+//! Tag inputs turn committed text into a row of removable tags.
+//!
+//! # Example
+//! ```no_run
+//! use iced::Element;
+//! use sweeten::widget::tag_input;
+//!
+//! struct State {
+//!    recipients: Vec<String>,
+//!    draft: String,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     DraftChanged(String),
+//!     RecipientAdded(String),
+//!     RecipientRemoved(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     tag_input("Add a recipient...", &state.draft, &state.recipients)
+//!         .on_input(Message::DraftChanged)
+//!         .on_add(Message::RecipientAdded)
+//!         .on_remove(Message::RecipientRemoved)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::DraftChanged(draft) => {
+//!             state.draft = draft;
+//!         }
+//!         Message::RecipientAdded(recipient) => {
+//!             state.recipients.push(recipient);
+//!             state.draft.clear();
+//!         }
+//!         Message::RecipientRemoved(index) => {
+//!             state.recipients.remove(index);
+//!         }
+//!     }
+//! }
+//! ```
+use iced::advanced::layout;
+use iced::advanced::renderer;
+use iced::advanced::text::{self, paragraph, Text};
+use iced::advanced::widget::tree::{self, Tree};
+use iced::advanced::widget::{Operation, Widget};
+use iced::advanced::{clipboard, mouse, overlay, Clipboard, Layout, Shell};
+use iced::alignment;
+use iced::event::{self, Event};
+use iced::keyboard::{self, key};
+use iced::touch;
+use iced::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Point,
+    Rectangle, Size, Theme, Vector,
+};
+
+use crate::widget::text_input::{self, cursor, PasteAction, TextInput};
+
+/// The padding around the text of a tag.
+const TAG_PADDING: Padding = Padding {
+    top: 2.0,
+    right: 6.0,
+    bottom: 2.0,
+    left: 6.0,
+};
+
+/// The narrowest the editable part of a [`TagInput`] gets before it wraps to
+/// a new line.
+const MIN_INPUT_WIDTH: f32 = 60.0;
+
+/// A field that turns committed text into removable tags.
+///
+/// Text is committed with Enter, a comma, or by pasting text with commas or
+/// line breaks. Backspace at the start of the editable part selects the last
+/// tag, and pressing it again removes it.
+#[allow(missing_debug_implementations)]
+pub struct TagInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tags: Vec<String>,
+    placeholder: String,
+    value: String,
+    input: TextInput<'a, Message, Theme, Renderer>,
+    is_editable: bool,
+    on_add: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_remove: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    font: Option<Renderer::Font>,
+    size: Option<Pixels>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> TagInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer,
+    <Theme as text_input::Catalog>::Class<'a>:
+        From<text_input::StyleFn<'a, Theme>>,
+{
+    /// Creates a new [`TagInput`] with the given placeholder, the value of
+    /// its editable part and its current tags.
+    pub fn new(
+        placeholder: &str,
+        value: &str,
+        tags: impl IntoIterator<Item = impl ToString>,
+    ) -> Self {
+        let input = TextInput::new(placeholder, value)
+            .padding([TAG_PADDING.top, 0.0])
+            .style(|theme: &Theme, status| text_input::Style {
+                background: Background::Color(Color::TRANSPARENT),
+                border: Border::default(),
+                ..text_input::Catalog::style(
+                    theme,
+                    &<Theme as text_input::Catalog>::default(),
+                    status,
+                )
+            });
+
+        TagInput {
+            tags: tags.into_iter().map(|tag| tag.to_string()).collect(),
+            placeholder: placeholder.to_owned(),
+            value: value.to_owned(),
+            input,
+            is_editable: false,
+            on_add: None,
+            on_remove: None,
+            font: None,
+            size: None,
+            width: Length::Fill,
+            padding: Padding::new(5.0),
+            spacing: 4.0,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> TagInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Sets the [`text_input::Id`] of the editable part of the [`TagInput`].
+    pub fn id(mut self, id: impl Into<text_input::Id>) -> Self {
+        self.input = self.input.id(id);
+        self
+    }
+
+    /// Sets the message that should be produced when the editable part of
+    /// the [`TagInput`] changes.
+    ///
+    /// If this method is not called, the [`TagInput`] will be disabled.
+    pub fn on_input(
+        mut self,
+        on_input: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.input = self.input.on_input(on_input);
+        self.is_editable = true;
+        self
+    }
+
+    /// Sets the message that should be produced when text is committed as a
+    /// new tag.
+    ///
+    /// The text is trimmed, and the application is expected to clear the
+    /// editable part of the [`TagInput`] in response.
+    pub fn on_add(mut self, on_add: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_add = Some(Box::new(on_add));
+        self
+    }
+
+    /// Sets the message that should be produced when the tag at the given
+    /// index is removed.
+    pub fn on_remove(
+        mut self,
+        on_remove: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_remove = Some(Box::new(on_remove));
+        self
+    }
+
+    /// Sets the message that should be produced when Enter is pressed with
+    /// an empty editable part.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.input = self.input.on_submit(message);
+        self
+    }
+
+    /// Sets the function that decides what happens to the clipboard contents
+    /// before they are pasted into the [`TagInput`].
+    ///
+    /// It runs before pasted text is split into tags. See
+    /// [`TextInput::paste_filter`] for details.
+    pub fn paste_filter(
+        mut self,
+        paste_filter: impl Fn(String) -> text_input::PasteAction<Message> + 'a,
+    ) -> Self {
+        self.input = self.input.paste_filter(paste_filter);
+        self
+    }
+
+    /// Sets the position of the [`TagInput`] in the keyboard navigation
+    /// order of [`focus_next`] and [`focus_previous`].
+    ///
+    /// See [`TextInput::tab_index`] for details.
+    ///
+    /// [`focus_next`]: text_input::focus_next
+    /// [`focus_previous`]: text_input::focus_previous
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.input = self.input.tab_index(tab_index);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`TagInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.input = self.input.font(font);
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the text size of the [`TagInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.input = self.input.size(size);
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the width of the [`TagInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TagInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the tags of the [`TagInput`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the style of the [`TagInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TagInput`].
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn commit(&self, text: &str, shell: &mut Shell<'_, Message>) {
+        let Some(on_add) = &self.on_add else {
+            return;
+        };
+
+        let tag = text.trim();
+
+        if !tag.is_empty() {
+            shell.publish((on_add)(tag.to_owned()));
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TagInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if state.selected.is_some_and(|index| index >= self.tags.len()) {
+            state.selected = None;
+        }
+
+        tree.children[0].diff(&self.input as &dyn Widget<_, _, _>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.size.unwrap_or_else(|| renderer.default_size());
+        let line_height = text::LineHeight::default();
+
+        let limits = limits.width(self.width).shrink(self.padding);
+        let max_width = limits.max().width;
+        let tag_height = f32::from(line_height.to_absolute(text_size))
+            + TAG_PADDING.top
+            + TAG_PADDING.bottom;
+
+        let text = |content| Text {
+            content,
+            bounds: Size::INFINITY,
+            size: text_size,
+            line_height,
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::default(),
+        };
+
+        state.remove.update(text("×"));
+        state
+            .tags
+            .resize_with(self.tags.len(), paragraph::Plain::default);
+
+        let remove_width = state.remove.min_width();
+        let mut position = Point::new(self.padding.left, self.padding.top);
+        let mut tags = Vec::with_capacity(self.tags.len());
+
+        // Tags flow left to right, wrapping to a new line when full.
+        let mut place = |width: f32| {
+            if position.x > self.padding.left
+                && position.x - self.padding.left + width > max_width
+            {
+                position.x = self.padding.left;
+                position.y += tag_height + self.spacing;
+            }
+
+            let placed = position;
+            position.x += width + self.spacing;
+            placed
+        };
+
+        for (paragraph, tag) in state.tags.iter_mut().zip(&self.tags) {
+            paragraph.update(text(tag));
+
+            let width = paragraph.min_width()
+                + self.spacing
+                + remove_width
+                + TAG_PADDING.horizontal();
+
+            tags.push(
+                layout::Node::new(Size::new(width, tag_height))
+                    .move_to(place(width)),
+            );
+        }
+
+        let input_position = place(MIN_INPUT_WIDTH);
+
+        // Without a bound, e.g. in a horizontal `scrollable`, the editable
+        // part is as wide as its value, or its placeholder while empty.
+        let input_width = if max_width.is_finite() {
+            max_width - (input_position.x - self.padding.left)
+        } else {
+            state.content.update(text(if self.value.is_empty() {
+                &self.placeholder
+            } else {
+                &self.value
+            }));
+
+            (state.content.min_width() + text_input::SCROLL_MARGIN)
+                .max(MIN_INPUT_WIDTH)
+        };
+
+        let input = self
+            .input
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(input_width, tag_height),
+                ),
+                None,
+            )
+            .move_to(input_position);
+
+        let width = tags
+            .iter()
+            .chain(std::iter::once(&input))
+            .map(|node| {
+                let bounds = node.bounds();
+
+                bounds.x + bounds.width - self.padding.left
+            })
+            .fold(0.0, f32::max);
+        let height = input_position.y - self.padding.top + tag_height;
+        let size = limits
+            .resolve(self.width, Length::Shrink, Size::new(width, height))
+            .expand(self.padding);
+
+        layout::Node::with_children(
+            size,
+            std::iter::once(input).chain(tags).collect(),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.input.operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let (state, children) = (&mut tree.state, &mut tree.children);
+        let state = state.downcast_mut::<State<Renderer::Paragraph>>();
+        let input_tree = &mut children[0];
+        let input_state = input_tree
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        let mut children_layout = layout.children();
+        let input_layout = children_layout.next().unwrap();
+
+        match &event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = *modifiers;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds())
+                else {
+                    state.selected = None;
+
+                    return Widget::on_event(
+                        &mut self.input,
+                        input_tree,
+                        event,
+                        input_layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    );
+                };
+
+                if !self.is_editable {
+                    return event::Status::Captured;
+                }
+
+                let tag = children_layout
+                    .enumerate()
+                    .find(|(_, tag)| tag.bounds().contains(position));
+
+                if let Some((index, tag)) = tag {
+                    let bounds = tag.bounds();
+                    let remove_x = bounds.x + bounds.width
+                        - TAG_PADDING.right
+                        - state.remove.min_width()
+                        - self.spacing;
+
+                    match &self.on_remove {
+                        Some(on_remove) if position.x >= remove_x => {
+                            shell.publish((on_remove)(index));
+                            state.selected = None;
+                        }
+                        _ => {
+                            state.selected = Some(index);
+                        }
+                    }
+
+                    if !input_state.is_focused() {
+                        input_state.focus();
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                state.selected = None;
+
+                if !cursor.is_over(input_layout.bounds()) {
+                    input_state.focus();
+                    input_state.move_cursor_to_end();
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key, text, ..
+            }) if input_state.is_focused() && self.is_editable => {
+                let is_at_start = matches!(
+                    input_state.cursor().state(self.input.value()),
+                    cursor::State::Index(0)
+                );
+
+                if let Some(index) = state.selected {
+                    match key.as_ref() {
+                        keyboard::Key::Named(
+                            key::Named::Backspace | key::Named::Delete,
+                        ) => {
+                            if let Some(on_remove) = &self.on_remove {
+                                shell.publish((on_remove)(index));
+                            }
+
+                            state.selected = None;
+
+                            return event::Status::Captured;
+                        }
+                        keyboard::Key::Named(key::Named::ArrowLeft) => {
+                            state.selected = Some(index.saturating_sub(1));
+
+                            return event::Status::Captured;
+                        }
+                        keyboard::Key::Named(key::Named::ArrowRight) => {
+                            state.selected = (index + 1 < self.tags.len())
+                                .then_some(index + 1);
+
+                            return event::Status::Captured;
+                        }
+                        _ => {
+                            state.selected = None;
+                        }
+                    }
+                }
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::Backspace)
+                        if is_at_start
+                            && !self.tags.is_empty()
+                            && self.on_remove.is_some() =>
+                    {
+                        state.selected = Some(self.tags.len() - 1);
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::Key::Named(key::Named::Enter)
+                        if self.on_add.is_some()
+                            && !self.value.trim().is_empty() =>
+                    {
+                        self.commit(&self.value, shell);
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::Key::Character("v")
+                        if state.keyboard_modifiers.command()
+                            && !state.keyboard_modifiers.alt()
+                            && self.on_add.is_some() =>
+                    {
+                        let content = clipboard
+                            .read(clipboard::Kind::Standard)
+                            .unwrap_or_default();
+
+                        match self.input.filter_paste(content) {
+                            PasteAction::Accept(content)
+                                if content.contains([',', '\n']) =>
+                            {
+                                let value = self.input.value();
+                                let selection = input_state.cursor();
+                                let (start, end) = (
+                                    selection.start(value),
+                                    selection.end(value),
+                                );
+                                let (left, right) =
+                                    (start.min(end), start.max(end));
+
+                                // The first piece completes the text before
+                                // the cursor, while the last one is left as
+                                // the draft, in front of the text after it.
+                                let separator = content
+                                    .rfind([',', '\n'])
+                                    .expect("Pasted text has a separator");
+                                let (tags, draft) = (
+                                    &content[..separator],
+                                    &content[separator + 1..],
+                                );
+                                let mut tags = tags.split([',', '\n']);
+
+                                self.commit(
+                                    &format!(
+                                        "{}{}",
+                                        value.until(left).contents(),
+                                        tags.next().unwrap_or_default()
+                                    ),
+                                    shell,
+                                );
+
+                                for tag in tags {
+                                    self.commit(tag, shell);
+                                }
+
+                                input_state.select_range(0, right);
+                                input_state.prepare_paste(draft);
+
+                                return Widget::on_event(
+                                    &mut self.input,
+                                    input_tree,
+                                    event,
+                                    input_layout,
+                                    cursor,
+                                    renderer,
+                                    clipboard,
+                                    shell,
+                                    viewport,
+                                );
+                            }
+                            PasteAction::Accept(content) => {
+                                // The editable part pastes the filtered text
+                                // without reading the clipboard again.
+                                input_state.prepare_paste(&content);
+
+                                return Widget::on_event(
+                                    &mut self.input,
+                                    input_tree,
+                                    event,
+                                    input_layout,
+                                    cursor,
+                                    renderer,
+                                    clipboard,
+                                    shell,
+                                    viewport,
+                                );
+                            }
+                            PasteAction::Reject => {}
+                            PasteAction::Split(message) => {
+                                shell.publish(message);
+                            }
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    _ if self.on_add.is_some()
+                        && text.as_deref() == Some(",") =>
+                    {
+                        self.commit(&self.value, shell);
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        Widget::on_event(
+            &mut self.input,
+            input_tree,
+            event,
+            input_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let input_tree = &tree.children[0];
+        let input_state = input_tree
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>();
+
+        let bounds = layout.bounds();
+        let mut children_layout = layout.children();
+        let input_layout = children_layout.next().unwrap();
+
+        let status = if !self.is_editable {
+            Status::Disabled
+        } else if input_state.is_focused() {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = Catalog::style(theme, &self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        for (index, (tag_layout, paragraph)) in
+            children_layout.zip(&state.tags).enumerate()
+        {
+            let tag_bounds = tag_layout.bounds();
+            let is_selected = state.selected == Some(index);
+
+            let (background, color) = if is_selected {
+                (style.selected_tag_background, style.selected_tag_text)
+            } else {
+                (style.tag_background, style.tag_text)
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: tag_bounds,
+                    border: style.tag_border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+
+            renderer.fill_paragraph(
+                paragraph.raw(),
+                Point::new(
+                    tag_bounds.x + TAG_PADDING.left,
+                    tag_bounds.center_y(),
+                ),
+                color,
+                *viewport,
+            );
+
+            renderer.fill_paragraph(
+                state.remove.raw(),
+                Point::new(
+                    tag_bounds.x + tag_bounds.width
+                        - TAG_PADDING.right
+                        - state.remove.min_width(),
+                    tag_bounds.center_y(),
+                ),
+                color,
+                *viewport,
+            );
+        }
+
+        self.input.draw(
+            input_tree,
+            renderer,
+            theme,
+            input_layout,
+            cursor,
+            None,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children_layout = layout.children();
+        let input_layout = children_layout.next().unwrap();
+
+        if !self.is_editable {
+            return mouse::Interaction::NotAllowed;
+        }
+
+        if children_layout.any(|tag| cursor.is_over(tag.bounds())) {
+            mouse::Interaction::Pointer
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            self.input.mouse_interaction(
+                &tree.children[0],
+                input_layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.input.overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TagInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        tag_input: TagInput<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(tag_input)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    tags: Vec<paragraph::Plain<P>>,
+    remove: paragraph::Plain<P>,
+    content: paragraph::Plain<P>,
+    selected: Option<usize>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+/// The possible status of a [`TagInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`TagInput`] can be interacted with.
+    Active,
+    /// The [`TagInput`] is being hovered.
+    Hovered,
+    /// The [`TagInput`] is focused.
+    Focused,
+    /// The [`TagInput`] cannot be interacted with.
+    Disabled,
+}
+
+/// The appearance of a tag input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the tag input.
+    pub background: Background,
+    /// The [`Border`] of the tag input.
+    pub border: Border,
+    /// The [`Background`] of a tag.
+    pub tag_background: Background,
+    /// The [`Border`] of a tag.
+    pub tag_border: Border,
+    /// The text [`Color`] of a tag.
+    pub tag_text: Color,
+    /// The [`Background`] of the selected tag.
+    pub selected_tag_background: Background,
+    /// The text [`Color`] of the selected tag.
+    pub selected_tag_text: Color,
+}
+
+/// The theme catalog of a [`TagInput`].
+pub trait Catalog: text_input::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+    ) -> Style;
+}
+
+/// A styling function for a [`TagInput`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+    ) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`TagInput`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: Background::Color(palette.background.base.color),
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        tag_background: Background::Color(palette.background.weak.color),
+        tag_border: Border {
+            radius: 2.0.into(),
+            ..Border::default()
+        },
+        tag_text: palette.background.weak.text,
+        selected_tag_background: Background::Color(
+            palette.primary.strong.color,
+        ),
+        selected_tag_text: palette.primary.strong.text,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            border: Border {
+                color: palette.background.base.text,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Focused => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Disabled => Style {
+            background: Background::Color(palette.background.weak.color),
+            tag_background: Background::Color(palette.background.strong.color),
+            ..active
+        },
+    }
+}
@@ -30,6 +30,11 @@ pub use cursor::Cursor;
 pub use value::{UnicodeSegmentation, Value};
 
 use editor::Editor;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+use crate::widget::operation::TabIndex;
+use crate::widget::overlay::menu::{self, Menu};
 
 use iced::advanced::mouse::click;
 use iced::advanced::text::{self, paragraph, Paragraph as _, Text};
@@ -37,7 +42,8 @@ use iced::advanced::widget::operation::{self, Operation};
 use iced::advanced::widget::tree::{self, Tree};
 use iced::advanced::widget::{self};
 use iced::advanced::{
-    clipboard, layout, mouse, renderer, Clipboard, Layout, Shell, Widget,
+    clipboard, layout, mouse, overlay, renderer, Clipboard, Layout, Shell,
+    Widget,
 };
 use iced::keyboard::key;
 use iced::time::{Duration, Instant};
@@ -46,6 +52,8 @@ use iced::{
     Element, Event, Length, Padding, Pixels, Point, Rectangle, Size, Task,
     Theme, Vector,
 };
+use std::any::Any;
+use std::sync::Arc;
 
 #[allow(missing_debug_implementations)]
 pub struct TextInput<
@@ -63,22 +71,46 @@ pub struct TextInput<
     is_secure: bool,
     font: Option<Renderer::Font>,
     width: Length,
+    min_width: f32,
+    max_width: f32,
     padding: Padding,
     size: Option<Pixels>,
     line_height: text::LineHeight,
     alignment: alignment::Horizontal,
-    on_focus: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_focus: Option<Box<dyn Fn(String, FocusReason) -> Message + 'a>>,
     on_blur: Option<Message>,
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    paste_filter: Option<Box<dyn Fn(String) -> PasteAction<Message> + 'a>>,
     on_submit: Option<Message>,
+    on_mode_change: Option<Box<dyn Fn(Mode) -> Message + 'a>>,
+    overtype: Option<bool>,
     icon: Option<Icon<Renderer::Font>>,
+    prefix: Option<String>,
+    suffix: Option<String>,
+    tab_index: i32,
+    word_boundary: WordBoundary,
+    auto_pairs: Vec<(char, char)>,
+    normalizations: Vec<Normalization>,
+    transform: Option<Case>,
+    triggers: Vec<Trigger<'a>>,
     class: Theme::Class<'a>,
+    completion_menu:
+        Option<Box<dyn CompletionMenu<'a, Message, Theme, Renderer> + 'a>>,
 }
 
 /// The default [`Padding`] of a [`TextInput`].
 pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
 
+/// The space kept between the cursor and the right edge of the text before
+/// the value starts scrolling.
+pub(crate) const SCROLL_MARGIN: f32 = 5.0;
+
+/// A common set of pairs for [`TextInput::auto_pair`]: parentheses, square
+/// brackets, curly braces and double quotes.
+pub const DEFAULT_AUTO_PAIRS: [(char, char); 4] =
+    [('(', ')'), ('[', ']'), ('{', '}'), ('"', '"')];
+
 impl<'a, Message, Theme, Renderer> TextInput<'a, Message, Theme, Renderer>
 where
     Message: Clone,
@@ -95,6 +127,8 @@ where
             is_secure: false,
             font: None,
             width: Length::Fill,
+            min_width: 0.0,
+            max_width: f32::INFINITY,
             padding: DEFAULT_PADDING,
             size: None,
             line_height: text::LineHeight::default(),
@@ -103,9 +137,21 @@ where
             on_blur: None,
             on_input: None,
             on_paste: None,
+            paste_filter: None,
             on_submit: None,
+            on_mode_change: None,
+            overtype: None,
             icon: None,
+            prefix: None,
+            suffix: None,
+            tab_index: 0,
+            word_boundary: WordBoundary::default(),
+            auto_pairs: Vec::new(),
+            normalizations: Vec::new(),
+            transform: None,
+            triggers: Vec::new(),
             class: Theme::default(),
+            completion_menu: None,
         }
     }
 
@@ -118,14 +164,17 @@ where
     /// Converts the [`TextInput`] into a secure password input.
     pub fn secure(mut self, is_secure: bool) -> Self {
         self.is_secure = is_secure;
+        self.value.set_secure(is_secure);
         self
     }
 
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused.
+    ///
+    /// The closure receives the current value and the [`FocusReason`].
     pub fn on_focus(
         mut self,
-        on_focus: impl Fn(String) -> Message + 'a,
+        on_focus: impl Fn(String, FocusReason) -> Message + 'a,
     ) -> Self {
         self.on_focus = Some(Box::new(on_focus));
         self
@@ -196,6 +245,39 @@ where
         self
     }
 
+    /// Sets whether the [`TextInput`] replaces the grapheme under the cursor
+    /// when typing, instead of inserting before it.
+    ///
+    /// The Insert key toggles the mode either way, and the toggled mode is
+    /// kept until the value passed here changes.
+    pub fn overtype(mut self, is_overtype: bool) -> Self {
+        self.overtype = Some(is_overtype);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Mode`] of the
+    /// [`TextInput`] is toggled with the Insert key.
+    pub fn on_mode_change(
+        mut self,
+        on_mode_change: impl Fn(Mode) -> Message + 'a,
+    ) -> Self {
+        self.on_mode_change = Some(Box::new(on_mode_change));
+        self
+    }
+
+    /// Sets the function that decides what happens to the clipboard contents
+    /// before they are pasted into the [`TextInput`].
+    ///
+    /// The function receives the raw clipboard text and runs before the value
+    /// changes. See [`PasteAction`] for the possible outcomes.
+    pub fn paste_filter(
+        mut self,
+        paste_filter: impl Fn(String) -> PasteAction<Message> + 'a,
+    ) -> Self {
+        self.paste_filter = Some(Box::new(paste_filter));
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -210,12 +292,140 @@ where
         self
     }
 
+    /// Sets the fixed, non-editable text displayed before the value of the
+    /// [`TextInput`].
+    ///
+    /// The prefix is not part of the value reported by `on_input`.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Sets the fixed, non-editable text displayed after the value of the
+    /// [`TextInput`].
+    ///
+    /// The suffix is not part of the value reported by `on_input`.
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Sets the position of the [`TextInput`] in the keyboard navigation
+    /// order of [`focus_next`] and [`focus_previous`].
+    ///
+    /// Negative values remove the [`TextInput`] from the navigation order,
+    /// but it can still be focused by a click or with [`focus`]. See
+    /// [`TabIndex`] for details.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    /// Sets the [`WordBoundary`] used by double-click selection and word
+    /// navigation in the [`TextInput`].
+    pub fn word_boundary(mut self, word_boundary: WordBoundary) -> Self {
+        self.word_boundary = word_boundary;
+        self
+    }
+
+    /// Enables auto-pairing of the given opening and closing characters in
+    /// the [`TextInput`]. See [`DEFAULT_AUTO_PAIRS`] for a common set.
+    ///
+    /// Typing an opener inserts its closer after the cursor, or wraps the
+    /// selection if there is one. Typing a closer steps over an existing one,
+    /// and Backspace between an empty pair deletes both.
+    ///
+    /// Auto-pairing is disabled for secure inputs.
+    pub fn auto_pair(
+        mut self,
+        pairs: impl IntoIterator<Item = (char, char)>,
+    ) -> Self {
+        self.auto_pairs = pairs.into_iter().collect();
+        self
+    }
+
+    /// Sets the [`Case`] applied to the text typed or pasted into the
+    /// [`TextInput`], before `on_input` is published.
+    ///
+    /// Only the new text is mapped, so the rest of the value is left as it
+    /// was given.
+    pub fn transform(mut self, case: Case) -> Self {
+        self.transform = Some(case);
+        self
+    }
+
+    /// Adds a completion trigger to the [`TextInput`].
+    ///
+    /// Typing the `trigger` character at the start of a word opens a menu
+    /// with the candidates returned by `candidates` for the text typed after
+    /// it. Choosing a candidate replaces the trigger and that text with the
+    /// candidate, so it should include the trigger if it is meant to stay
+    /// (e.g. `@user`).
+    ///
+    /// The menu can be navigated with the arrow keys; Enter or Tab choose the
+    /// highlighted candidate and Escape dismisses it.
+    ///
+    /// `candidates` is only called again once the text after the trigger
+    /// changes.
+    pub fn trigger<T>(
+        mut self,
+        trigger: char,
+        candidates: impl Fn(&str) -> Vec<T> + 'a,
+    ) -> Self
+    where
+        T: ToString,
+        Message: 'a,
+        Theme: menu::Catalog + 'a,
+        Renderer: 'a,
+    {
+        if self.completion_menu.is_none() {
+            self.completion_menu = Some(Box::new(Styled {
+                class: <Theme as menu::Catalog>::default(),
+            }));
+        }
+
+        self.triggers.push(Trigger {
+            character: trigger,
+            candidates: Box::new(move |query| {
+                candidates(query).iter().map(T::to_string).collect()
+            }),
+        });
+        self
+    }
+
+    /// Adds a [`Normalization`] applied to the value of the [`TextInput`]
+    /// when it is blurred or submitted.
+    ///
+    /// Normalizations are applied in the order they were added. If the value
+    /// changes, the normalized value is published through `on_input` before
+    /// `on_blur` or `on_submit`.
+    pub fn normalize(mut self, normalization: Normalization) -> Self {
+        self.normalizations.push(normalization);
+        self
+    }
+
     /// Sets the width of the [`TextInput`].
+    ///
+    /// With [`Length::Shrink`], the [`TextInput`] is exactly as wide as its
+    /// value, or its placeholder while empty, within the bounds set by
+    /// [`min_width`](Self::min_width) and [`max_width`](Self::max_width).
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
         self
     }
 
+    /// Sets the minimum width of the [`TextInput`].
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets the maximum width of the [`TextInput`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
     /// Sets the [`Padding`] of the [`TextInput`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -256,6 +466,20 @@ where
         self
     }
 
+    /// Sets the style of the completion [`Menu`].
+    ///
+    /// [`Menu`]: menu::Menu
+    #[must_use]
+    pub fn menu_style(self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        Message: 'a,
+        Theme: menu::Catalog + 'a,
+        Renderer: 'a,
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class(Box::new(style) as menu::StyleFn<'a, Theme>)
+    }
+
     /// Sets the style class of the [`TextInput`].
     #[must_use]
     pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
@@ -263,6 +487,135 @@ where
         self
     }
 
+    /// Sets the style class of the completion [`Menu`].
+    ///
+    /// [`Menu`]: menu::Menu
+    #[must_use]
+    pub fn menu_class(
+        mut self,
+        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self
+    where
+        Message: 'a,
+        Theme: menu::Catalog + 'a,
+        Renderer: 'a,
+    {
+        self.completion_menu = Some(Box::new(Styled {
+            class: class.into(),
+        }));
+        self
+    }
+
+    /// Returns the [`Value`] of the [`TextInput`].
+    pub(crate) fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Runs the paste filter of the [`TextInput`], if any, on the given
+    /// clipboard contents.
+    pub(crate) fn filter_paste(&self, content: String) -> PasteAction<Message> {
+        match &self.paste_filter {
+            Some(paste_filter) => (paste_filter)(content),
+            None => PasteAction::Accept(content),
+        }
+    }
+
+    /// Publishes the normalized value of the [`TextInput`] through
+    /// `on_input`, if any [`Normalization`] changes it.
+    fn publish_normalized(&self, shell: &mut Shell<'_, Message>) {
+        let Some(on_input) = &self.on_input else {
+            return;
+        };
+
+        let value = Zeroizing::new(self.value.contents());
+        let normalized = self
+            .normalizations
+            .iter()
+            .fold(value.clone(), |value, normalization| {
+                Zeroizing::new(normalization.apply(&value))
+            });
+
+        if normalized != value {
+            shell.publish((on_input)(normalized.to_string()));
+        }
+    }
+
+    /// Opens the completion menu if the cursor follows one of the triggers
+    /// of the [`TextInput`], or closes it otherwise.
+    fn update_completion<P: text::Paragraph>(&self, state: &mut State<P>) {
+        let Some((trigger, start, end)) = self.find_trigger(state) else {
+            state.completion = Completion::default();
+            return;
+        };
+
+        let query = self.value.select(start + 1, end).to_string();
+
+        // Moving the cursor or pressing keys that leave the query untouched
+        // keeps the candidates, along with the hovered one.
+        if state.completion.trigger == Some(trigger.character)
+            && state.completion.start == start
+            && state.completion.query == query
+        {
+            return;
+        }
+
+        let candidates = (trigger.candidates)(&query);
+
+        state.completion = Completion {
+            is_open: true,
+            start,
+            end,
+            trigger: Some(trigger.character),
+            query,
+            hovered: (!candidates.is_empty()).then_some(0),
+            candidates,
+            menu: menu::State::new(),
+        };
+    }
+
+    /// Finds the trigger that the cursor follows, along with the graphemes it
+    /// covers up to the cursor.
+    fn find_trigger<P: text::Paragraph>(
+        &self,
+        state: &State<P>,
+    ) -> Option<(&Trigger<'a>, usize, usize)> {
+        if self.triggers.is_empty()
+            || self.is_secure
+            || self.on_input.is_none()
+            || !state.is_focused()
+        {
+            return None;
+        }
+
+        let cursor::State::Index(end) = state.cursor.state(&self.value) else {
+            return None;
+        };
+
+        let grapheme_at =
+            |index: usize| self.value.select(index, index + 1).to_string();
+
+        for start in (0..end).rev() {
+            let grapheme = grapheme_at(start);
+
+            if grapheme.chars().all(char::is_whitespace) {
+                return None;
+            }
+
+            let is_word_start = start == 0
+                || grapheme_at(start - 1).chars().all(char::is_whitespace);
+
+            let trigger = self.triggers.iter().find(|trigger| {
+                grapheme.chars().eq(std::iter::once(trigger.character))
+            });
+
+            if let Some(trigger) = trigger.filter(|_| is_word_start) {
+                return Some((trigger, start, end));
+            }
+        }
+
+        None
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -281,8 +634,12 @@ where
         let padding = self.padding.fit(Size::ZERO, limits.max());
         let height = self.line_height.to_absolute(text_size);
 
-        let limits = limits.width(self.width).shrink(padding);
-        let text_bounds = limits.resolve(self.width, height, Size::ZERO);
+        let limits = limits
+            .width(self.width)
+            .min_width(self.min_width)
+            .max_width(self.max_width)
+            .shrink(padding);
+        let mut text_bounds = limits.resolve(self.width, height, Size::ZERO);
 
         let placeholder_text = Text {
             font,
@@ -306,6 +663,10 @@ where
             ..placeholder_text
         });
 
+        let mut icon_width = 0.0;
+        let mut prefix_width = 0.0;
+        let mut suffix_width = 0.0;
+
         if let Some(icon) = &self.icon {
             let mut content = [0; 4];
 
@@ -323,50 +684,115 @@ where
 
             state.icon.update(icon_text);
 
-            let icon_width = state.icon.min_width();
+            icon_width = state.icon.min_width() + icon.spacing;
+        }
 
-            let (text_position, icon_position) = match icon.side {
-                Side::Left => (
-                    Point::new(
-                        padding.left + icon_width + icon.spacing,
-                        padding.top,
-                    ),
-                    Point::new(padding.left, padding.top),
-                ),
-                Side::Right => (
-                    Point::new(padding.left, padding.top),
-                    Point::new(
-                        padding.left + text_bounds.width - icon_width,
-                        padding.top,
-                    ),
+        if let Some(prefix) = &self.prefix {
+            state.prefix.update(Text {
+                content: prefix.as_str(),
+                ..placeholder_text
+            });
+
+            prefix_width = state.prefix.min_width();
+        }
+
+        if let Some(suffix) = &self.suffix {
+            state.suffix.update(Text {
+                content: suffix.as_str(),
+                ..placeholder_text
+            });
+
+            suffix_width = state.suffix.min_width();
+        }
+
+        if self.width == Length::Shrink {
+            let content_width = if value.is_empty() {
+                state.placeholder.min_width()
+            } else {
+                state.value.min_width()
+            };
+
+            // The scroll margin keeps the text still when the cursor sits
+            // at its end.
+            let intrinsic_width = icon_width
+                + prefix_width
+                + content_width
+                + SCROLL_MARGIN
+                + suffix_width;
+
+            text_bounds = limits.resolve(
+                self.width,
+                height,
+                Size::new(intrinsic_width, 0.0),
+            );
+        }
+
+        let mut text_position = Point::new(padding.left, padding.top);
+        let mut text_width = text_bounds.width;
+        let mut children = Vec::new();
+
+        if let Some(icon) = &self.icon {
+            let icon_position = match icon.side {
+                Side::Left => {
+                    text_position.x += icon_width;
+
+                    Point::new(padding.left, padding.top)
+                }
+                Side::Right => Point::new(
+                    padding.left + text_bounds.width - state.icon.min_width(),
+                    padding.top,
                 ),
             };
 
-            let text_node = layout::Node::new(
-                text_bounds - Size::new(icon_width + icon.spacing, 0.0),
-            )
-            .move_to(text_position);
+            text_width -= icon_width;
 
-            let icon_node =
-                layout::Node::new(Size::new(icon_width, text_bounds.height))
-                    .move_to(icon_position);
+            children.push(
+                layout::Node::new(Size::new(
+                    state.icon.min_width(),
+                    text_bounds.height,
+                ))
+                .move_to(icon_position),
+            );
+        }
 
-            layout::Node::with_children(
-                text_bounds.expand(padding),
-                vec![text_node, icon_node],
-            )
-        } else {
-            let text = layout::Node::new(text_bounds)
-                .move_to(Point::new(padding.left, padding.top));
+        if self.prefix.is_some() {
+            children.push(
+                layout::Node::new(Size::new(prefix_width, text_bounds.height))
+                    .move_to(text_position),
+            );
 
-            layout::Node::with_children(text_bounds.expand(padding), vec![text])
+            text_position.x += prefix_width;
+            text_width -= prefix_width;
         }
+
+        if self.suffix.is_some() {
+            text_width -= suffix_width;
+
+            children.push(
+                layout::Node::new(Size::new(suffix_width, text_bounds.height))
+                    .move_to(Point::new(
+                        text_position.x + text_width.max(0.0),
+                        padding.top,
+                    )),
+            );
+        }
+
+        let text_node = layout::Node::new(Size::new(
+            text_width.max(0.0),
+            text_bounds.height,
+        ))
+        .move_to(text_position);
+
+        children.insert(0, text_node);
+
+        layout::Node::with_children(text_bounds.expand(padding), children)
     }
 
     /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
     /// [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
+    #[allow(clippy::too_many_arguments)]
     pub fn draw(
         &self,
         tree: &Tree,
@@ -393,6 +819,8 @@ where
 
         let status = if is_disabled {
             Status::Disabled
+        } else if state.focus_reason() == Some(FocusReason::Keyboard) {
+            Status::FocusVisible
         } else if state.is_focused() {
             Status::Focused
         } else if is_mouse_over {
@@ -401,7 +829,7 @@ where
             Status::Active
         };
 
-        let style = theme.style(&self.class, status);
+        let style = Catalog::style(theme, &self.class, status);
 
         renderer.fill_quad(
             renderer::Quad {
@@ -423,6 +851,28 @@ where
             );
         }
 
+        if self.prefix.is_some() {
+            let prefix_bounds = children_layout.next().unwrap().bounds();
+
+            renderer.fill_paragraph(
+                state.prefix.raw(),
+                Point::new(prefix_bounds.x, prefix_bounds.center_y()),
+                style.prefix,
+                *viewport,
+            );
+        }
+
+        if self.suffix.is_some() {
+            let suffix_bounds = children_layout.next().unwrap().bounds();
+
+            renderer.fill_paragraph(
+                state.suffix.raw(),
+                Point::new(suffix_bounds.x, suffix_bounds.center_y()),
+                style.suffix,
+                *viewport,
+            );
+        }
+
         let text = value.to_string();
 
         let (cursor, offset, is_selecting) = if let Some(focus) = state
@@ -442,10 +892,41 @@ where
                     let is_cursor_visible = !is_disabled
                         && ((focus.now - focus.updated_at).as_millis()
                             / CURSOR_BLINK_INTERVAL_MILLIS)
-                            % 2
-                            == 0;
+                            .is_multiple_of(2);
+
+                    let cursor = if is_cursor_visible && state.is_overtype {
+                        // The block covers the grapheme to be replaced, or
+                        // half an em past the end of the value.
+                        let width = if position < value.len() {
+                            let (next_width, _) =
+                                measure_cursor_and_scroll_offset(
+                                    state.value.raw(),
+                                    text_bounds,
+                                    position + 1,
+                                );
+
+                            next_width - text_value_width
+                        } else {
+                            self.size
+                                .unwrap_or_else(|| renderer.default_size())
+                                .0
+                                / 2.0
+                        };
 
-                    let cursor = if is_cursor_visible {
+                        Some((
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: (text_bounds.x + text_value_width)
+                                        .floor(),
+                                    y: text_bounds.y,
+                                    width: width.max(1.0),
+                                    height: text_bounds.height,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.selection,
+                        ))
+                    } else if is_cursor_visible {
                         Some((
                             renderer::Quad {
                                 bounds: Rectangle {
@@ -569,7 +1050,10 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::<Renderer::Paragraph>::new())
+        let mut state = State::<Renderer::Paragraph>::new();
+        state.apply_overtype(self.overtype);
+
+        tree::State::new(state)
     }
 
     fn diff(&self, tree: &mut Tree) {
@@ -579,6 +1063,8 @@ where
         if self.on_input.is_none() {
             state.is_pasting = None;
         }
+
+        state.apply_overtype(self.overtype);
     }
 
     fn size(&self) -> Size<Length> {
@@ -606,8 +1092,14 @@ where
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+        operation.custom(
+            &mut TabIndex(self.tab_index),
+            self.id.as_ref().map(|id| &id.0),
+        );
         operation.focusable(state, self.id.as_ref().map(|id| &id.0));
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation
+            .custom(&mut state.is_focused, self.id.as_ref().map(|id| &id.0));
     }
 
     fn on_event(
@@ -621,18 +1113,45 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
-        let update_cache = |state, value| {
+        let update_cache = |state, value: &Value| {
+            // Secure values never reach the paragraph cache.
+            let secure_value = self.is_secure.then(|| value.secure());
+
             replace_paragraph(
                 renderer,
                 state,
                 layout,
-                value,
+                secure_value.as_ref().unwrap_or(value),
                 self.font,
                 self.size,
                 self.line_height,
             );
         };
 
+        // Focus lost through an operation (e.g. `focus_next`) can only be
+        // reported once the widget sees its next event.
+        if std::mem::take(&mut state::<Renderer>(tree).is_blur_pending) {
+            self.publish_normalized(shell);
+
+            if let Some(on_blur) = &self.on_blur {
+                shell.publish(on_blur.clone());
+            }
+        }
+
+        // The same goes for focus gained through an operation.
+        if let Some(focus) = &mut state::<Renderer>(tree).is_focused {
+            if !focus.is_published {
+                focus.is_published = true;
+
+                if let Some(on_focus) = &self.on_focus {
+                    shell.publish((on_focus)(
+                        self.value.contents(),
+                        focus.reason,
+                    ));
+                }
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -641,23 +1160,37 @@ where
                 let click_position = cursor.position_over(layout.bounds());
 
                 state.is_focused = if click_position.is_some() {
-                    state.is_focused.or_else(|| {
-                        let now = Instant::now();
+                    let focus = state.is_focused.map_or_else(
+                        || {
+                            let now = Instant::now();
+
+                            if let Some(on_focus) = &self.on_focus {
+                                shell.publish((on_focus)(
+                                    self.value.contents(),
+                                    FocusReason::Click,
+                                ));
+                            }
 
-                        if let Some(on_focus) = &self.on_focus {
-                            let message = (on_focus)(format!("{}", self.value));
-                            shell.publish(message);
-                        }
+                            Focus {
+                                updated_at: now,
+                                now,
+                                is_window_focused: true,
+                                is_published: true,
+                                reason: FocusReason::Click,
+                            }
+                        },
+                        |focus| Focus {
+                            reason: FocusReason::Click,
+                            ..focus
+                        },
+                    );
 
-                        Some(Focus {
-                            updated_at: now,
-                            now,
-                            is_window_focused: true,
-                        })
-                    })
+                    Some(focus)
                 } else {
-                    if let Some(on_blur) = &self.on_blur {
-                        if state.is_focused() {
+                    if state.is_focused() {
+                        self.publish_normalized(shell);
+
+                        if let Some(on_blur) = &self.on_blur {
                             shell.publish(on_blur.clone());
                         }
                     }
@@ -665,6 +1198,22 @@ where
                     None
                 };
 
+                let is_over_adornment = {
+                    let adornments = layout
+                        .children()
+                        .skip(1 + usize::from(self.icon.is_some()));
+
+                    adornments
+                        .into_iter()
+                        .any(|adornment| cursor.is_over(adornment.bounds()))
+                };
+
+                // Clicking a prefix or suffix focuses the input, but leaves
+                // the cursor untouched.
+                if click_position.is_some() && is_over_adornment {
+                    return event::Status::Captured;
+                }
+
                 if let Some(cursor_position) = click_position {
                     let text_layout = layout.children().next().unwrap();
 
@@ -729,8 +1278,14 @@ where
                                 .unwrap_or(0);
 
                                 state.cursor.select_range(
-                                    self.value.previous_start_of_word(position),
-                                    self.value.next_end_of_word(position),
+                                    self.value.previous_start_of_word_by(
+                                        position,
+                                        &self.word_boundary,
+                                    ),
+                                    self.value.next_end_of_word_by(
+                                        position,
+                                        &self.word_boundary,
+                                    ),
                                 );
                             }
 
@@ -744,6 +1299,8 @@ where
 
                     state.last_click = Some(click);
 
+                    self.update_completion(state);
+
                     return event::Status::Captured;
                 }
             }
@@ -801,11 +1358,85 @@ where
                     let modifiers = state.keyboard_modifiers;
                     focus.updated_at = Instant::now();
 
+                    if state.completion.is_open {
+                        let completion = &mut state.completion;
+                        let count = completion.candidates.len();
+
+                        match key.as_ref() {
+                            keyboard::Key::Named(key::Named::ArrowDown)
+                                if count > 0 =>
+                            {
+                                completion.hovered = Some(
+                                    completion
+                                        .hovered
+                                        .map_or(0, |index| (index + 1) % count),
+                                );
+
+                                return event::Status::Captured;
+                            }
+                            keyboard::Key::Named(key::Named::ArrowUp)
+                                if count > 0 =>
+                            {
+                                completion.hovered = Some(
+                                    completion
+                                        .hovered
+                                        .map_or(count - 1, |index| {
+                                            (index + count - 1) % count
+                                        }),
+                                );
+
+                                return event::Status::Captured;
+                            }
+                            keyboard::Key::Named(
+                                key::Named::Enter | key::Named::Tab,
+                            ) => {
+                                let completed = completion
+                                    .hovered
+                                    .and_then(|index| {
+                                        completion.candidates.get(index)
+                                    })
+                                    .map(|candidate| {
+                                        complete(
+                                            &self.value,
+                                            completion.start,
+                                            completion.end,
+                                            candidate,
+                                        )
+                                    });
+
+                                if let (
+                                    Some((contents, position)),
+                                    Some(on_input),
+                                ) = (completed, &self.on_input)
+                                {
+                                    state.completion = Completion::default();
+                                    state.cursor.move_to(position);
+                                    self.value = Value::new(&contents);
+
+                                    shell.publish((on_input)(contents));
+
+                                    update_cache(state, &self.value);
+
+                                    return event::Status::Captured;
+                                }
+                            }
+                            keyboard::Key::Named(key::Named::Escape) => {
+                                state.completion = Completion::default();
+
+                                return event::Status::Captured;
+                            }
+                            _ => {}
+                        }
+                    }
+
                     match key.as_ref() {
                         keyboard::Key::Character("c")
-                            if state.keyboard_modifiers.command()
-                                && !self.is_secure =>
+                            if state.keyboard_modifiers.command() =>
                         {
+                            if self.is_secure {
+                                return event::Status::Captured;
+                            }
+
                             if let Some((start, end)) =
                                 state.cursor.selection(&self.value)
                             {
@@ -818,13 +1449,16 @@ where
                             return event::Status::Captured;
                         }
                         keyboard::Key::Character("x")
-                            if state.keyboard_modifiers.command()
-                                && !self.is_secure =>
+                            if state.keyboard_modifiers.command() =>
                         {
                             let Some(on_input) = &self.on_input else {
                                 return event::Status::Ignored;
                             };
 
+                            if self.is_secure {
+                                return event::Status::Captured;
+                            }
+
                             if let Some((start, end)) =
                                 state.cursor.selection(&self.value)
                             {
@@ -856,21 +1490,45 @@ where
                             let content = match state.is_pasting.take() {
                                 Some(content) => content,
                                 None => {
-                                    let content: String = clipboard
+                                    let content = clipboard
                                         .read(clipboard::Kind::Standard)
-                                        .unwrap_or_default()
-                                        .chars()
-                                        .filter(|c| !c.is_control())
-                                        .collect();
-
-                                    Value::new(&content)
+                                        .unwrap_or_default();
+
+                                    let content =
+                                        match self.filter_paste(content) {
+                                            PasteAction::Accept(content) => {
+                                                Zeroizing::new(content)
+                                            }
+                                            PasteAction::Reject => {
+                                                return event::Status::Captured;
+                                            }
+                                            PasteAction::Split(message) => {
+                                                shell.publish(message);
+
+                                                return event::Status::Captured;
+                                            }
+                                        };
+
+                                    pasted(&content, self.is_secure)
                                 }
                             };
 
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
 
-                            editor.paste(content.clone());
+                            match &self.transform {
+                                Some(case) => {
+                                    let text =
+                                        Zeroizing::new(content.contents());
+                                    let text = Zeroizing::new(case.apply(
+                                        &text,
+                                        editor.is_at_word_start(),
+                                    ));
+
+                                    editor.paste(Value::new(&text));
+                                }
+                                None => editor.paste(content.clone()),
+                            }
 
                             let message = if let Some(paste) = &self.on_paste {
                                 (paste)(editor.contents())
@@ -882,6 +1540,7 @@ where
                             state.is_pasting = Some(content);
 
                             update_cache(state, &self.value);
+                            self.update_completion(state);
 
                             return event::Status::Captured;
                         }
@@ -905,10 +1564,47 @@ where
                         if let Some(c) =
                             text.chars().next().filter(|c| !c.is_control())
                         {
+                            let pairs = if self.is_secure {
+                                &[][..]
+                            } else {
+                                &self.auto_pairs[..]
+                            };
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
 
-                            editor.insert(c);
+                            if pairs.iter().any(|&(_, closer)| closer == c)
+                                && editor.skip(c)
+                            {
+                                focus.updated_at = Instant::now();
+
+                                return event::Status::Captured;
+                            }
+
+                            if let Some(&(opener, closer)) =
+                                pairs.iter().find(|&&(opener, _)| opener == c)
+                            {
+                                editor.insert_pair(opener, closer);
+                            } else {
+                                let typed =
+                                    Zeroizing::new(match &self.transform {
+                                        Some(case) => case.apply(
+                                            c.encode_utf8(&mut [0; 4]),
+                                            editor.is_at_word_start(),
+                                        ),
+                                        None => String::from(c),
+                                    });
+
+                                // A case mapping may turn one character into
+                                // several (e.g. `ß` into `SS`).
+                                for c in typed.chars() {
+                                    if state.is_overtype {
+                                        editor.overtype(c);
+                                    } else {
+                                        editor.insert(c);
+                                    }
+                                }
+                            }
 
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
@@ -916,6 +1612,7 @@ where
                             focus.updated_at = Instant::now();
 
                             update_cache(state, &self.value);
+                            self.update_completion(state);
 
                             return event::Status::Captured;
                         }
@@ -924,6 +1621,7 @@ where
                     match key.as_ref() {
                         keyboard::Key::Named(key::Named::Enter) => {
                             if let Some(on_submit) = self.on_submit.clone() {
+                                self.publish_normalized(shell);
                                 shell.publish(on_submit);
                             }
                         }
@@ -940,15 +1638,25 @@ where
                                         state.cursor.end(&self.value);
                                     state.cursor.select_range(0, cursor_pos);
                                 } else {
-                                    state
-                                        .cursor
-                                        .select_left_by_words(&self.value);
+                                    state.cursor.select_left_by_words(
+                                        &self.value,
+                                        &self.word_boundary,
+                                    );
                                 }
                             }
 
+                            let pairs = if self.is_secure {
+                                &[][..]
+                            } else {
+                                &self.auto_pairs[..]
+                            };
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
-                            editor.backspace();
+
+                            if !editor.backspace_pair(pairs) {
+                                editor.backspace();
+                            }
 
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
@@ -971,9 +1679,10 @@ where
                                         self.value.len(),
                                     );
                                 } else {
-                                    state
-                                        .cursor
-                                        .select_right_by_words(&self.value);
+                                    state.cursor.select_right_by_words(
+                                        &self.value,
+                                        &self.word_boundary,
+                                    );
                                 }
                             }
 
@@ -1033,13 +1742,15 @@ where
                         keyboard::Key::Named(key::Named::ArrowLeft) => {
                             if modifiers.jump() && !self.is_secure {
                                 if modifiers.shift() {
-                                    state
-                                        .cursor
-                                        .select_left_by_words(&self.value);
+                                    state.cursor.select_left_by_words(
+                                        &self.value,
+                                        &self.word_boundary,
+                                    );
                                 } else {
-                                    state
-                                        .cursor
-                                        .move_left_by_words(&self.value);
+                                    state.cursor.move_left_by_words(
+                                        &self.value,
+                                        &self.word_boundary,
+                                    );
                                 }
                             } else if modifiers.shift() {
                                 state.cursor.select_left(&self.value);
@@ -1050,13 +1761,15 @@ where
                         keyboard::Key::Named(key::Named::ArrowRight) => {
                             if modifiers.jump() && !self.is_secure {
                                 if modifiers.shift() {
-                                    state
-                                        .cursor
-                                        .select_right_by_words(&self.value);
+                                    state.cursor.select_right_by_words(
+                                        &self.value,
+                                        &self.word_boundary,
+                                    );
                                 } else {
-                                    state
-                                        .cursor
-                                        .move_right_by_words(&self.value);
+                                    state.cursor.move_right_by_words(
+                                        &self.value,
+                                        &self.word_boundary,
+                                    );
                                 }
                             } else if modifiers.shift() {
                                 state.cursor.select_right(&self.value);
@@ -1064,6 +1777,15 @@ where
                                 state.cursor.move_right(&self.value);
                             }
                         }
+                        keyboard::Key::Named(key::Named::Insert)
+                            if !modifiers.shift() =>
+                        {
+                            state.is_overtype = !state.is_overtype;
+
+                            if let Some(on_mode_change) = &self.on_mode_change {
+                                shell.publish((on_mode_change)(state.mode()));
+                            }
+                        }
                         keyboard::Key::Named(key::Named::Escape) => {
                             state.is_focused = None;
                             state.is_dragging = false;
@@ -1072,6 +1794,8 @@ where
                             state.keyboard_modifiers =
                                 keyboard::Modifiers::default();
 
+                            self.publish_normalized(shell);
+
                             if let Some(on_blur) = &self.on_blur {
                                 shell.publish(on_blur.clone());
                             }
@@ -1086,6 +1810,8 @@ where
                         _ => {}
                     }
 
+                    self.update_completion(state);
+
                     return event::Status::Captured;
                 }
             }
@@ -1172,6 +1898,92 @@ where
         self.draw(tree, renderer, theme, layout, cursor, None, viewport);
     }
 
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if !state.is_focused()
+            || !state.completion.is_open
+            || state.completion.candidates.is_empty()
+        {
+            return None;
+        }
+
+        let on_input = self.on_input.as_ref()?;
+        let completion_menu = self.completion_menu.as_ref()?;
+
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        // The menu follows the caret, but stays within the input if it can.
+        let caret = {
+            let (position, offset) = measure_cursor_and_scroll_offset(
+                state.value.raw(),
+                text_bounds,
+                state.completion.end,
+            );
+
+            let alignment_offset = alignment_offset(
+                text_bounds.width,
+                state.value.raw().min_width(),
+                self.alignment,
+            );
+
+            text_bounds.x + alignment_offset + position - offset
+        };
+
+        let right = bounds.x + bounds.width;
+        let width = (right - caret).max(bounds.width / 3.0);
+        let x = caret.min(right - width);
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let value = self.value.clone();
+
+        let State {
+            cursor, completion, ..
+        } = state;
+
+        let Completion {
+            is_open,
+            start,
+            end,
+            candidates,
+            hovered,
+            menu,
+            ..
+        } = completion;
+
+        let (start, end) = (*start, *end);
+
+        Some(completion_menu.overlay(
+            menu,
+            candidates,
+            hovered,
+            Box::new(move |candidate: String| {
+                let (contents, position) =
+                    complete(&value, start, end, &candidate);
+
+                cursor.move_to(position);
+                *is_open = false;
+
+                (on_input)(contents)
+            }),
+            MenuLayout {
+                position: Point::new(x, bounds.y) + translation,
+                target_height: bounds.height,
+                width,
+                padding: self.padding,
+                font,
+                size: self.size,
+            },
+        ))
+    }
+
     fn mouse_interaction(
         &self,
         _state: &Tree,
@@ -1236,6 +2048,187 @@ impl<Font> From<iced::widget::text_input::Icon<Font>> for Icon<Font> {
     }
 }
 
+/// The rules deciding where words start and end in a [`TextInput`].
+#[derive(Clone, Default)]
+pub enum WordBoundary {
+    /// Words follow the Unicode word segmentation rules.
+    ///
+    /// This is the default.
+    #[default]
+    Unicode,
+    /// Words are delimited by whitespace only.
+    Whitespace,
+    /// Words are made of alphanumeric characters and underscores, like
+    /// identifiers in most programming languages.
+    Identifier,
+    /// Words are path components, delimited by whitespace and path
+    /// separators.
+    Path,
+    /// Words are made of the graphemes for which the predicate returns
+    /// `true`.
+    Custom(Arc<dyn Fn(&str) -> bool>),
+}
+
+impl WordBoundary {
+    /// Creates a [`WordBoundary::Custom`] with the given predicate.
+    pub fn custom(predicate: impl Fn(&str) -> bool + 'static) -> Self {
+        Self::Custom(Arc::new(predicate))
+    }
+
+    /// Returns the predicate telling whether a grapheme belongs to a word, or
+    /// `None` for [`WordBoundary::Unicode`].
+    fn predicate(&self) -> Option<&dyn Fn(&str) -> bool> {
+        fn is_not_whitespace(grapheme: &str) -> bool {
+            !grapheme.chars().all(char::is_whitespace)
+        }
+
+        fn is_identifier(grapheme: &str) -> bool {
+            grapheme.chars().all(|c| c.is_alphanumeric() || c == '_')
+        }
+
+        fn is_path_component(grapheme: &str) -> bool {
+            !grapheme
+                .chars()
+                .all(|c| c.is_whitespace() || c == '/' || c == '\\')
+        }
+
+        match self {
+            Self::Unicode => None,
+            Self::Whitespace => Some(&is_not_whitespace),
+            Self::Identifier => Some(&is_identifier),
+            Self::Path => Some(&is_path_component),
+            Self::Custom(predicate) => Some(predicate.as_ref()),
+        }
+    }
+}
+
+impl std::fmt::Debug for WordBoundary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unicode => f.write_str("Unicode"),
+            Self::Whitespace => f.write_str("Whitespace"),
+            Self::Identifier => f.write_str("Identifier"),
+            Self::Path => f.write_str("Path"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// A rule applied to the value of a [`TextInput`] when it is blurred or
+/// submitted.
+///
+/// See [`TextInput::normalize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Removes leading and trailing whitespace.
+    Trim,
+    /// Replaces every run of whitespace with a single space.
+    CollapseWhitespace,
+    /// Applies Unicode canonical composition (NFC).
+    Nfc,
+    /// Applies Unicode compatibility composition (NFKC).
+    Nfkc,
+    /// Converts the value to uppercase.
+    Uppercase,
+    /// Converts the value to lowercase.
+    Lowercase,
+}
+
+impl Normalization {
+    fn apply(self, value: &str) -> String {
+        match self {
+            Self::Trim => value.trim().to_owned(),
+            Self::CollapseWhitespace => {
+                let mut collapsed = String::with_capacity(value.len());
+                let mut words = value.split_whitespace();
+
+                // Keep the outer whitespace, trimming is up to `Trim`.
+                if value.starts_with(char::is_whitespace) {
+                    collapsed.push(' ');
+                }
+
+                if let Some(word) = words.next() {
+                    collapsed.push_str(word);
+
+                    for word in words {
+                        collapsed.push(' ');
+                        collapsed.push_str(word);
+                    }
+
+                    if value.ends_with(char::is_whitespace) {
+                        collapsed.push(' ');
+                    }
+                }
+
+                collapsed
+            }
+            Self::Nfc => value.nfc().collect(),
+            Self::Nfkc => value.nfkc().collect(),
+            Self::Uppercase => value.to_uppercase(),
+            Self::Lowercase => value.to_lowercase(),
+        }
+    }
+}
+
+/// The case mapping applied to a [`TextInput`] while typing.
+///
+/// See [`TextInput::transform`].
+#[derive(Clone)]
+pub enum Case {
+    /// Converts the value to uppercase.
+    Upper,
+    /// Converts the value to lowercase.
+    Lower,
+    /// Converts the first letter of every word to uppercase, leaving the
+    /// rest as typed.
+    Title,
+    /// Maps the typed or pasted text with the given function.
+    Custom(Arc<dyn Fn(&str) -> String>),
+}
+
+impl Case {
+    /// Creates a [`Case::Custom`] with the given function.
+    pub fn custom(f: impl Fn(&str) -> String + 'static) -> Self {
+        Self::Custom(Arc::new(f))
+    }
+
+    /// Maps the given text, which starts a word if `is_word_start` is true.
+    fn apply(&self, text: &str, is_word_start: bool) -> String {
+        match self {
+            Self::Upper => text.to_uppercase(),
+            Self::Lower => text.to_lowercase(),
+            Self::Title => {
+                let mut title = String::with_capacity(text.len());
+                let mut is_word_start = is_word_start;
+
+                for c in text.chars() {
+                    if is_word_start {
+                        title.extend(c.to_uppercase());
+                    } else {
+                        title.push(c);
+                    }
+
+                    is_word_start = c.is_whitespace();
+                }
+
+                title
+            }
+            Self::Custom(f) => f(text),
+        }
+    }
+}
+
+impl std::fmt::Debug for Case {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Upper => f.write_str("Upper"),
+            Self::Lower => f.write_str("Lower"),
+            Self::Title => f.write_str("Title"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
 /// The side of a [`TextInput`].
 #[derive(Debug, Clone)]
 pub enum Side {
@@ -1269,9 +2262,9 @@ impl From<Id> for widget::Id {
     }
 }
 
-impl Into<Id> for widget::Id {
-    fn into(self) -> Id {
-        Id(self)
+impl From<widget::Id> for Id {
+    fn from(id: widget::Id) -> Self {
+        Self(id)
     }
 }
 
@@ -1296,32 +2289,42 @@ where
     widget::operate(widget::operation::focusable::focus(id.0))
 }
 
-/// Produces a [`Task`] that focuses the next focusable widget
-/// and then applies the provided function to create a resulting task.
+/// Produces a [`Task`] that focuses the next focusable widget, honoring any
+/// [`TabIndex`], and then applies the provided function to create a resulting
+/// task.
 pub fn focus_next<T, F>(f: F) -> Task<T>
 where
     T: Send + 'static,
     F: Send + Sync + Fn(widget::Id) -> Task<T> + 'static,
 {
-    widget::operate(widget::operation::focusable::focus_next()).chain(
-        widget::operate(widget::operation::focusable::find_focused())
-            .then(move |id| f(id)),
+    widget::operate(crate::widget::operation::focus_next(None)).chain(
+        widget::operate(widget::operation::focusable::find_focused()).then(f),
     )
 }
 
-/// Produces a [`Task`] that focuses the previous focusable widget
-/// and then applies the provided function to create a resulting task.
+/// Produces a [`Task`] that focuses the previous focusable widget, honoring
+/// any [`TabIndex`], and then applies the provided function to create a
+/// resulting task.
 pub fn focus_previous<T, F>(f: F) -> Task<T>
 where
     T: Send + 'static,
     F: Send + Sync + Fn(widget::Id) -> Task<T> + 'static,
 {
-    widget::operate(widget::operation::focusable::focus_previous()).chain(
-        widget::operate(widget::operation::focusable::find_focused())
-            .then(move |id| f(id)),
+    widget::operate(crate::widget::operation::focus_previous(None)).chain(
+        widget::operate(widget::operation::focusable::find_focused()).then(f),
     )
 }
 
+/// Sets the [`FocusReason`] of a focused [`TextInput`], given the focus
+/// state it exposes through [`Operation::custom`].
+///
+/// Any other state is left untouched.
+pub(crate) fn mark_focus_reason(state: &mut dyn Any, reason: FocusReason) {
+    if let Some(Some(focus)) = state.downcast_mut::<Option<Focus>>() {
+        focus.reason = reason;
+    }
+}
+
 /// Produces a [`Task`] that moves the cursor of the [`TextInput`] with the given [`Id`] to the
 /// end.
 pub fn move_cursor_to_end<T>(id: impl Into<Id>) -> Task<T>
@@ -1373,9 +2376,15 @@ pub struct State<P: text::Paragraph> {
     value: paragraph::Plain<P>,
     placeholder: paragraph::Plain<P>,
     icon: paragraph::Plain<P>,
+    prefix: paragraph::Plain<P>,
+    suffix: paragraph::Plain<P>,
     is_focused: Option<Focus>,
+    is_blur_pending: bool,
     is_dragging: bool,
     is_pasting: Option<Value>,
+    is_overtype: bool,
+    applied_overtype: Option<bool>,
+    completion: Completion,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
@@ -1388,11 +2397,193 @@ fn state<Renderer: text::Renderer>(
     tree.state.downcast_mut::<State<Renderer::Paragraph>>()
 }
 
+#[allow(clippy::type_complexity)]
+struct Trigger<'a> {
+    character: char,
+    candidates: Box<dyn Fn(&str) -> Vec<String> + 'a>,
+}
+
+/// The completion menu of a [`TextInput`], styled with a [`menu::Catalog`]
+/// class.
+///
+/// It is boxed so the [`Catalog`] of a [`TextInput`] does not need to extend
+/// [`menu::Catalog`] unless a [`TextInput::trigger`] is set.
+trait CompletionMenu<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn overlay<'b>(
+        &'b self,
+        state: &'b mut menu::State,
+        candidates: &'b [String],
+        hovered: &'b mut Option<usize>,
+        on_selected: Box<dyn FnMut(String) -> Message + 'b>,
+        layout: MenuLayout<Renderer::Font>,
+    ) -> overlay::Element<'b, Message, Theme, Renderer>;
+}
+
+/// Where and how the completion menu of a [`TextInput`] is laid out.
+struct MenuLayout<Font> {
+    position: Point,
+    target_height: f32,
+    width: f32,
+    padding: Padding,
+    font: Font,
+    size: Option<Pixels>,
+}
+
+struct Styled<'a, Theme>
+where
+    Theme: menu::Catalog,
+{
+    class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> CompletionMenu<'a, Message, Theme, Renderer>
+    for Styled<'a, Theme>
+where
+    Message: 'a,
+    Theme: menu::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn overlay<'b>(
+        &'b self,
+        state: &'b mut menu::State,
+        candidates: &'b [String],
+        hovered: &'b mut Option<usize>,
+        on_selected: Box<dyn FnMut(String) -> Message + 'b>,
+        layout: MenuLayout<Renderer::Font>,
+    ) -> overlay::Element<'b, Message, Theme, Renderer> {
+        let mut menu = Menu::new(
+            state,
+            candidates,
+            hovered,
+            on_selected,
+            None,
+            None,
+            &self.class,
+        )
+        .width(layout.width)
+        .padding(layout.padding)
+        .font(layout.font);
+
+        if let Some(size) = layout.size {
+            menu = menu.text_size(size);
+        }
+
+        menu.overlay(layout.position, layout.target_height)
+    }
+}
+
+/// The completion menu of a [`TextInput`], covering the graphemes from the
+/// trigger at `start` to the cursor at `end`.
+///
+/// The candidates are kept for as long as the `trigger` and the `query`
+/// typed after it stay the same.
+#[derive(Debug, Default)]
+struct Completion {
+    is_open: bool,
+    start: usize,
+    end: usize,
+    trigger: Option<char>,
+    query: String,
+    candidates: Vec<String>,
+    hovered: Option<usize>,
+    menu: menu::State,
+}
+
+impl Clone for Completion {
+    fn clone(&self) -> Self {
+        // The menu state only holds layout caches, so a fresh one will do.
+        Self {
+            is_open: self.is_open,
+            start: self.start,
+            end: self.end,
+            trigger: self.trigger,
+            query: self.query.clone(),
+            candidates: self.candidates.clone(),
+            hovered: self.hovered,
+            menu: menu::State::new(),
+        }
+    }
+}
+
+/// Turns accepted clipboard contents into the [`Value`] to paste, without
+/// its control characters.
+fn pasted(content: &str, is_secure: bool) -> Value {
+    let content = Zeroizing::new(
+        content
+            .chars()
+            .filter(|c| !c.is_control())
+            .collect::<String>(),
+    );
+
+    let mut value = Value::new(&content);
+    value.set_secure(is_secure);
+
+    value
+}
+
+/// Replaces the graphemes between `start` and `end` of the [`Value`] with the
+/// candidate, returning the new contents and the cursor position after it.
+fn complete(
+    value: &Value,
+    start: usize,
+    end: usize,
+    candidate: &str,
+) -> (String, usize) {
+    let contents = format!(
+        "{}{candidate}{}",
+        value.until(start),
+        value.select(end, value.len()),
+    );
+
+    (contents, start + Value::new(candidate).len())
+}
+
 #[derive(Debug, Clone, Copy)]
 struct Focus {
     updated_at: Instant,
     now: Instant,
     is_window_focused: bool,
+    is_published: bool,
+    reason: FocusReason,
+}
+
+/// The outcome of the paste filter of a [`TextInput`].
+///
+/// See [`TextInput::paste_filter`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PasteAction<Message> {
+    /// Paste the given text, which may differ from the clipboard contents.
+    Accept(String),
+    /// Paste nothing.
+    Reject,
+    /// Paste nothing into this [`TextInput`] and publish the given message
+    /// instead, so the application can spread the text across several
+    /// fields (e.g. the boxes of a one-time code).
+    Split(Message),
+}
+
+/// The editing mode of a [`TextInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Typed text is inserted before the cursor.
+    Insert,
+    /// Typed text replaces the grapheme under the cursor.
+    Overtype,
+}
+
+/// The way a [`TextInput`] gained focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusReason {
+    /// The [`TextInput`] was clicked or touched.
+    Click,
+    /// The [`TextInput`] was reached with [`focus_next`] or
+    /// [`focus_previous`].
+    Keyboard,
+    /// The [`TextInput`] was focused with the [`focus`] task.
+    Programmatic,
 }
 
 impl<P: text::Paragraph> State<P> {
@@ -1406,6 +2597,32 @@ impl<P: text::Paragraph> State<P> {
         self.is_focused.is_some()
     }
 
+    /// Returns the [`FocusReason`] of the [`TextInput`], if it is focused.
+    pub fn focus_reason(&self) -> Option<FocusReason> {
+        self.is_focused.map(|focus| focus.reason)
+    }
+
+    /// Returns the editing [`Mode`] of the [`TextInput`].
+    pub fn mode(&self) -> Mode {
+        if self.is_overtype {
+            Mode::Overtype
+        } else {
+            Mode::Insert
+        }
+    }
+
+    /// Switches to the overtype mode given to the [`TextInput`], unless it
+    /// was already applied, so a mode toggled with the Insert key survives
+    /// rebuilds.
+    fn apply_overtype(&mut self, overtype: Option<bool>) {
+        if let Some(is_overtype) = overtype {
+            if self.applied_overtype != overtype {
+                self.is_overtype = is_overtype;
+                self.applied_overtype = overtype;
+            }
+        }
+    }
+
     /// Returns the [`Cursor`] of the [`TextInput`].
     pub fn cursor(&self) -> Cursor {
         self.cursor
@@ -1419,6 +2636,8 @@ impl<P: text::Paragraph> State<P> {
             updated_at: now,
             now,
             is_window_focused: true,
+            is_published: false,
+            reason: FocusReason::Programmatic,
         });
 
         self.move_cursor_to_end();
@@ -1429,6 +2648,17 @@ impl<P: text::Paragraph> State<P> {
         self.is_focused = None;
     }
 
+    /// Makes the next paste insert the given text, which already went
+    /// through the paste filter, instead of reading the clipboard.
+    pub(crate) fn prepare_paste(&mut self, content: &str) {
+        self.is_pasting = Some(pasted(content, false));
+    }
+
+    /// Selects the graphemes between `start` and `end`.
+    pub(crate) fn select_range(&mut self, start: usize, end: usize) {
+        self.cursor.select_range(start, end);
+    }
+
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
     pub fn move_cursor_to_front(&mut self) {
         self.cursor.move_to(0);
@@ -1460,6 +2690,8 @@ impl<P: text::Paragraph> operation::Focusable for State<P> {
     }
 
     fn unfocus(&mut self) {
+        self.is_blur_pending |= State::is_focused(self);
+
         State::unfocus(self);
     }
 }
@@ -1516,7 +2748,8 @@ fn measure_cursor_and_scroll_offset(
         .grapheme_position(0, cursor_index)
         .unwrap_or(Point::ORIGIN);
 
-    let offset = ((grapheme_position.x + 5.0) - text_bounds.width).max(0.0);
+    let offset =
+        ((grapheme_position.x + SCROLL_MARGIN) - text_bounds.width).max(0.0);
 
     (grapheme_position.x, offset)
 }
@@ -1588,6 +2821,8 @@ pub enum Status {
     Hovered,
     /// The [`TextInput`] is focused.
     Focused,
+    /// The [`TextInput`] is focused and was reached with the keyboard.
+    FocusVisible,
     /// The [`TextInput`] cannot be interacted with.
     Disabled,
 }
@@ -1601,6 +2836,10 @@ pub struct Style {
     pub border: Border,
     /// The [`Color`] of the icon of the text input.
     pub icon: Color,
+    /// The [`Color`] of the prefix of the text input.
+    pub prefix: Color,
+    /// The [`Color`] of the suffix of the text input.
+    pub suffix: Color,
     /// The [`Color`] of the placeholder of the text input.
     pub placeholder: Color,
     /// The [`Color`] of the value of the text input.
@@ -1650,6 +2889,8 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             color: palette.background.strong.color,
         },
         icon: palette.background.weak.text,
+        prefix: palette.background.weak.text,
+        suffix: palette.background.weak.text,
         placeholder: palette.background.strong.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
@@ -1671,6 +2912,14 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             },
             ..active
         },
+        Status::FocusVisible => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                width: 2.0,
+                ..active.border
+            },
+            ..active
+        },
         Status::Disabled => Style {
             background: Background::Color(palette.background.weak.color),
             value: active.placeholder,
@@ -1,5 +1,5 @@
 //! Track the cursor of a text input.
-use super::Value;
+use super::{Value, WordBoundary};
 
 /// The cursor of a text input.
 #[derive(Debug, Copy, Clone)]
@@ -66,8 +66,12 @@ impl Cursor {
         self.move_right_by_amount(value, 1);
     }
 
-    pub(crate) fn move_right_by_words(&mut self, value: &Value) {
-        self.move_to(value.next_end_of_word(self.right(value)));
+    pub(crate) fn move_right_by_words(
+        &mut self,
+        value: &Value,
+        boundary: &WordBoundary,
+    ) {
+        self.move_to(value.next_end_of_word_by(self.right(value), boundary));
     }
 
     pub(crate) fn move_right_by_amount(&mut self, value: &Value, amount: usize) {
@@ -87,8 +91,14 @@ impl Cursor {
         }
     }
 
-    pub(crate) fn move_left_by_words(&mut self, value: &Value) {
-        self.move_to(value.previous_start_of_word(self.left(value)));
+    pub(crate) fn move_left_by_words(
+        &mut self,
+        value: &Value,
+        boundary: &WordBoundary,
+    ) {
+        self.move_to(
+            value.previous_start_of_word_by(self.left(value), boundary),
+        );
     }
 
     pub(crate) fn select_range(&mut self, start: usize, end: usize) {
@@ -123,24 +133,44 @@ impl Cursor {
         }
     }
 
-    pub(crate) fn select_left_by_words(&mut self, value: &Value) {
+    pub(crate) fn select_left_by_words(
+        &mut self,
+        value: &Value,
+        boundary: &WordBoundary,
+    ) {
         match self.state(value) {
             State::Index(index) => {
-                self.select_range(index, value.previous_start_of_word(index));
+                self.select_range(
+                    index,
+                    value.previous_start_of_word_by(index, boundary),
+                );
             }
             State::Selection { start, end } => {
-                self.select_range(start, value.previous_start_of_word(end));
+                self.select_range(
+                    start,
+                    value.previous_start_of_word_by(end, boundary),
+                );
             }
         }
     }
 
-    pub(crate) fn select_right_by_words(&mut self, value: &Value) {
+    pub(crate) fn select_right_by_words(
+        &mut self,
+        value: &Value,
+        boundary: &WordBoundary,
+    ) {
         match self.state(value) {
             State::Index(index) => {
-                self.select_range(index, value.next_end_of_word(index));
+                self.select_range(
+                    index,
+                    value.next_end_of_word_by(index, boundary),
+                );
             }
             State::Selection { start, end } => {
-                self.select_range(start, value.next_end_of_word(end));
+                self.select_range(
+                    start,
+                    value.next_end_of_word_by(end, boundary),
+                );
             }
         }
     }
@@ -1,5 +1,8 @@
+use super::cursor::State;
 use super::{Cursor, Value};
 
+use zeroize::Zeroizing;
+
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
@@ -11,7 +14,7 @@ impl<'a> Editor<'a> {
     }
 
     pub fn contents(&self) -> String {
-        self.value.to_string()
+        self.value.contents()
     }
 
     pub fn insert(&mut self, character: char) {
@@ -20,20 +23,82 @@ impl<'a> Editor<'a> {
             self.value.remove_many(left, right);
         }
 
-        self.value.insert(self.cursor.end(self.value), character);
-        self.cursor.move_right(self.value);
+        let added = self.value.insert(self.cursor.end(self.value), character);
+        self.cursor.move_right_by_amount(self.value, added);
+    }
+
+    pub fn overtype(&mut self, character: char) {
+        if self.cursor.selection(self.value).is_none() {
+            let end = self.cursor.end(self.value);
+
+            if end < self.value.len() {
+                self.value.remove(end);
+            }
+        }
+
+        self.insert(character);
+    }
+
+    pub fn insert_pair(&mut self, opener: char, closer: char) {
+        match self.cursor.selection(self.value) {
+            Some((start, end)) => {
+                let _ = self.value.insert(end, closer);
+                let opened = self.value.insert(start, opener);
+                self.cursor.select_range(start + opened, end + opened);
+            }
+            None => {
+                let position = self.cursor.end(self.value);
+
+                let _ = self.value.insert(position, closer);
+                let opened = self.value.insert(position, opener);
+                self.cursor.move_to(position + opened);
+            }
+        }
+    }
+
+    pub fn skip(&mut self, character: char) -> bool {
+        if self.cursor.selection(self.value).is_some() {
+            return false;
+        }
+
+        let position = self.cursor.end(self.value);
+        let next = Zeroizing::new(
+            self.value.select(position, position + 1).to_string(),
+        );
+
+        if next.chars().eq(std::iter::once(character)) {
+            self.cursor.move_to(position + 1);
+            true
+        } else {
+            false
+        }
     }
 
     pub fn paste(&mut self, content: Value) {
-        let length = content.len();
         if let Some((left, right)) = self.cursor.selection(self.value) {
             self.cursor.move_left(self.value);
             self.value.remove_many(left, right);
         }
 
-        self.value.insert_many(self.cursor.end(self.value), content);
+        let added =
+            self.value.insert_many(self.cursor.end(self.value), content);
 
-        self.cursor.move_right_by_amount(self.value, length);
+        self.cursor.move_right_by_amount(self.value, added);
+    }
+
+    /// Returns whether text inserted at the cursor starts a word.
+    pub fn is_at_word_start(&self) -> bool {
+        let index = match self.cursor.state(self.value) {
+            State::Index(index) => index,
+            State::Selection { start, end } => start.min(end),
+        };
+
+        index == 0
+            || self
+                .value
+                .grapheme(index - 1)
+                .chars()
+                .all(char::is_whitespace)
     }
 
     pub fn backspace(&mut self) {
@@ -53,6 +118,33 @@ impl<'a> Editor<'a> {
         }
     }
 
+    pub fn backspace_pair(&mut self, pairs: &[(char, char)]) -> bool {
+        if self.cursor.selection(self.value).is_some() {
+            return false;
+        }
+
+        let position = self.cursor.end(self.value);
+
+        if position == 0 {
+            return false;
+        }
+
+        let around = Zeroizing::new(
+            self.value.select(position - 1, position + 1).to_string(),
+        );
+
+        let is_empty_pair = pairs
+            .iter()
+            .any(|&(opener, closer)| around.chars().eq([opener, closer]));
+
+        if is_empty_pair {
+            self.value.remove_many(position - 1, position + 1);
+            self.cursor.move_to(position - 1);
+        }
+
+        is_empty_pair
+    }
+
     pub fn delete(&mut self) {
         match self.cursor.selection(self.value) {
             Some(_) => {
@@ -1,22 +1,55 @@
 pub use unicode_segmentation::UnicodeSegmentation;
 
+use super::WordBoundary;
+
+use zeroize::Zeroize;
+
 /// The value of a [`TextInput`].
 ///
+/// The contents are kept in a single buffer, along with the byte offsets of
+/// its grapheme boundaries.
+///
+/// The contents of a secure [`Value`] are wiped from memory when they are
+/// removed or when the [`Value`] is dropped, so secrets typed into a secure
+/// [`TextInput`] do not linger.
+///
 /// [`TextInput`]: super::TextInput
-// TODO: Reduce allocations, cache results (?)
 #[derive(Debug, Clone)]
 pub struct Value {
-    graphemes: Vec<String>,
+    text: String,
+    /// The byte offset of every grapheme boundary, including both ends.
+    boundaries: Vec<usize>,
+    is_secure: bool,
 }
 
 impl Value {
     /// Creates a new [`Value`] from a string slice.
     pub fn new(string: &str) -> Self {
-        let graphemes = UnicodeSegmentation::graphemes(string, true)
-            .map(String::from)
+        let boundaries = UnicodeSegmentation::grapheme_indices(string, true)
+            .map(|(i, _)| i)
+            .chain(std::iter::once(string.len()))
             .collect();
 
-        Self { graphemes }
+        Self {
+            text: String::from(string),
+            boundaries,
+            is_secure: false,
+        }
+    }
+
+    /// Creates a new secure [`Value`] from a string slice, which wipes its
+    /// contents from memory when they are removed or dropped.
+    pub fn new_secure(string: &str) -> Self {
+        let mut value = Self::new(string);
+        value.set_secure(true);
+
+        value
+    }
+
+    /// Sets whether the [`Value`] wipes its contents from memory when they
+    /// are removed or dropped.
+    pub(crate) fn set_secure(&mut self, is_secure: bool) {
+        self.is_secure = is_secure;
     }
 
     /// Returns whether the [`Value`] is empty or not.
@@ -28,107 +61,271 @@ impl Value {
 
     /// Returns the total amount of graphemes in the [`Value`].
     pub fn len(&self) -> usize {
-        self.graphemes.len()
+        self.boundaries.len() - 1
     }
 
     /// Returns the position of the previous start of a word from the given
     /// grapheme `index`.
     pub fn previous_start_of_word(&self, index: usize) -> usize {
-        let previous_string =
-            &self.graphemes[..index.min(self.graphemes.len())].concat();
-
-        UnicodeSegmentation::split_word_bound_indices(previous_string as &str)
-            .filter(|(_, word)| !word.trim_start().is_empty())
-            .next_back()
-            .map(|(i, previous_word)| {
-                index
-                    - UnicodeSegmentation::graphemes(previous_word, true)
-                        .count()
-                    - UnicodeSegmentation::graphemes(
-                        &previous_string[i + previous_word.len()..] as &str,
-                        true,
-                    )
-                    .count()
-            })
+        let previous_string = &self.text[..self.offset(index)];
+
+        UnicodeSegmentation::split_word_bound_indices(previous_string)
+            .rfind(|(_, word)| !word.trim_start().is_empty())
+            .map(|(i, _)| self.index_at(i))
             .unwrap_or(0)
     }
 
     /// Returns the position of the next end of a word from the given grapheme
     /// `index`.
     pub fn next_end_of_word(&self, index: usize) -> usize {
-        let next_string = &self.graphemes[index..].concat();
+        let offset = self.offset(index);
+        let next_string = &self.text[offset..];
 
-        UnicodeSegmentation::split_word_bound_indices(next_string as &str)
+        UnicodeSegmentation::split_word_bound_indices(next_string)
             .find(|(_, word)| !word.trim_start().is_empty())
-            .map(|(i, next_word)| {
-                index
-                    + UnicodeSegmentation::graphemes(next_word, true).count()
-                    + UnicodeSegmentation::graphemes(
-                        &next_string[..i] as &str,
-                        true,
-                    )
-                    .count()
-            })
+            .map(|(i, next_word)| self.index_at(offset + i + next_word.len()))
             .unwrap_or(self.len())
     }
 
+    /// Returns the position of the previous start of a word from the given
+    /// grapheme `index`, as delimited by the given [`WordBoundary`].
+    pub fn previous_start_of_word_by(
+        &self,
+        index: usize,
+        boundary: &WordBoundary,
+    ) -> usize {
+        let Some(is_word) = boundary.predicate() else {
+            return self.previous_start_of_word(index);
+        };
+
+        let mut index = index.min(self.len());
+
+        while index > 0 && !is_word(self.grapheme(index - 1)) {
+            index -= 1;
+        }
+
+        while index > 0 && is_word(self.grapheme(index - 1)) {
+            index -= 1;
+        }
+
+        index
+    }
+
+    /// Returns the position of the next end of a word from the given grapheme
+    /// `index`, as delimited by the given [`WordBoundary`].
+    pub fn next_end_of_word_by(
+        &self,
+        index: usize,
+        boundary: &WordBoundary,
+    ) -> usize {
+        let Some(is_word) = boundary.predicate() else {
+            return self.next_end_of_word(index);
+        };
+
+        let mut index = index.min(self.len());
+
+        while index < self.len() && !is_word(self.grapheme(index)) {
+            index += 1;
+        }
+
+        while index < self.len() && is_word(self.grapheme(index)) {
+            index += 1;
+        }
+
+        index
+    }
+
     /// Returns a new [`Value`] containing the graphemes from `start` until the
     /// given `end`.
     pub fn select(&self, start: usize, end: usize) -> Self {
-        let graphemes =
-            self.graphemes[start.min(self.len())..end.min(self.len())].to_vec();
+        let boundaries =
+            &self.boundaries[start.min(self.len())..=end.min(self.len())];
+        let first = boundaries[0];
 
-        Self { graphemes }
+        Self {
+            text: String::from(
+                &self.text[first..boundaries[boundaries.len() - 1]],
+            ),
+            boundaries: boundaries.iter().map(|b| b - first).collect(),
+            is_secure: self.is_secure,
+        }
     }
 
     /// Returns a new [`Value`] containing the graphemes until the given
     /// `index`.
     pub fn until(&self, index: usize) -> Self {
-        let graphemes = self.graphemes[..index.min(self.len())].to_vec();
+        self.select(0, index)
+    }
 
-        Self { graphemes }
+    /// Inserts a new `char` at the given grapheme `index`, returning the
+    /// amount of graphemes added.
+    ///
+    /// The `char` may combine with the grapheme before it, in which case no
+    /// grapheme is added.
+    pub fn insert(&mut self, index: usize, c: char) -> usize {
+        let mut buffer = [0; 4];
+
+        self.insert_str(index, c.encode_utf8(&mut buffer))
     }
 
-    /// Inserts a new `char` at the given grapheme `index`.
-    pub fn insert(&mut self, index: usize, c: char) {
-        self.graphemes.insert(index, c.to_string());
+    /// Inserts a bunch of graphemes at the given grapheme `index`, returning
+    /// the amount of graphemes added.
+    ///
+    /// The graphemes at either end may combine with their neighbors, in which
+    /// case fewer graphemes are added.
+    pub fn insert_many(&mut self, index: usize, mut value: Value) -> usize {
+        // The inserted copy is as sensitive as the rest of the contents.
+        value.is_secure |= self.is_secure;
 
-        self.graphemes =
-            UnicodeSegmentation::graphemes(&self.to_string() as &str, true)
-                .map(String::from)
-                .collect();
+        self.insert_str(index, &value.text)
     }
 
-    /// Inserts a bunch of graphemes at the given grapheme `index`.
-    pub fn insert_many(&mut self, index: usize, mut value: Value) {
-        let _ = self
-            .graphemes
-            .splice(index..index, value.graphemes.drain(..));
+    /// Inserts a string at the given grapheme `index`, returning the amount
+    /// of graphemes added.
+    fn insert_str(&mut self, index: usize, inserted: &str) -> usize {
+        let len = self.len();
+        let offset = self.boundaries[index];
+
+        self.reserve(inserted.len());
+        self.text.insert_str(offset, inserted);
+
+        // The new text may combine with its neighbors, so graphemes are
+        // segmented again from the previous one until the old boundaries
+        // line up.
+        let start = index.saturating_sub(1);
+        let from = self.boundaries[start];
+
+        let mut tail = self
+            .boundaries
+            .split_off(index + 1)
+            .into_iter()
+            .map(|boundary| boundary + inserted.len())
+            .peekable();
+
+        self.boundaries.truncate(start + 1);
+
+        for (i, grapheme) in
+            UnicodeSegmentation::grapheme_indices(&self.text[from..], true)
+        {
+            let end = from + i + grapheme.len();
+
+            while tail.next_if(|&boundary| boundary < end).is_some() {}
+
+            if tail.peek() == Some(&end) {
+                break;
+            }
+
+            self.boundaries.push(end);
+        }
+
+        self.boundaries.extend(tail);
+
+        self.len().saturating_sub(len)
     }
 
     /// Removes the grapheme at the given `index`.
     pub fn remove(&mut self, index: usize) {
-        let _ = self.graphemes.remove(index);
+        self.remove_many(index, index + 1);
     }
 
     /// Removes the graphemes from `start` to `end`.
     pub fn remove_many(&mut self, start: usize, end: usize) {
-        let _ = self.graphemes.splice(start..end, std::iter::empty());
+        let (from, to) = (self.boundaries[start], self.boundaries[end]);
+        let removed = to - from;
+
+        self.text.replace_range(from..to, "");
+
+        // Shifting the rest of the text leaves a stale copy of its end past
+        // the new length, which is overwritten in place.
+        if self.is_secure {
+            let len = self.text.len();
+            self.text.extend(std::iter::repeat_n('\0', removed));
+            self.text.truncate(len);
+        }
+
+        let _ = self.boundaries.drain(start + 1..=end);
+
+        self.boundaries[start + 1..]
+            .iter_mut()
+            .for_each(|boundary| *boundary -= removed);
+    }
+
+    /// Returns the contents of the [`Value`] as a [`String`].
+    ///
+    /// Unlike `to_string`, the [`String`] is allocated once, so growing it
+    /// leaves no partial copies of the contents behind.
+    pub(crate) fn contents(&self) -> String {
+        self.text.clone()
     }
 
     /// Returns a new [`Value`] with all its graphemes replaced with the
     /// dot ('•') character.
     pub fn secure(&self) -> Self {
+        const DOT: &str = "•";
+
         Self {
-            graphemes: std::iter::repeat(String::from("•"))
-                .take(self.graphemes.len())
-                .collect(),
+            text: DOT.repeat(self.len()),
+            boundaries: (0..=self.len()).map(|i| i * DOT.len()).collect(),
+            is_secure: false,
+        }
+    }
+
+    /// Returns the grapheme at the given `index`.
+    pub(crate) fn grapheme(&self, index: usize) -> &str {
+        &self.text[self.boundaries[index]..self.boundaries[index + 1]]
+    }
+
+    /// Returns the byte offset of the given grapheme `index`.
+    fn offset(&self, index: usize) -> usize {
+        self.boundaries[index.min(self.len())]
+    }
+
+    /// Returns the grapheme index of the given byte `offset`.
+    fn index_at(&self, offset: usize) -> usize {
+        self.boundaries
+            .partition_point(|&boundary| boundary < offset)
+    }
+
+    /// Makes room for `additional` bytes, wiping the old buffer of a secure
+    /// [`Value`] if it has to be reallocated.
+    fn reserve(&mut self, additional: usize) {
+        if !self.is_secure {
+            self.text.reserve(additional);
+            return;
+        }
+
+        if self.text.capacity() - self.text.len() >= additional {
+            return;
+        }
+
+        let capacity =
+            (self.text.len() + additional).max(self.text.capacity() * 2);
+        let mut text = String::with_capacity(capacity);
+        text.push_str(&self.text);
+
+        self.text.zeroize();
+        self.text = text;
+    }
+}
+
+impl Zeroize for Value {
+    fn zeroize(&mut self) {
+        self.text.zeroize();
+        self.boundaries.clear();
+        self.boundaries.push(0);
+    }
+}
+
+impl Drop for Value {
+    fn drop(&mut self) {
+        if self.is_secure {
+            self.zeroize();
         }
     }
 }
 
 impl std::fmt::Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.graphemes.concat())
+        f.write_str(&self.text)
     }
 }
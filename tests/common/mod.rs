@@ -0,0 +1,151 @@
+//! A widget tree driven like a runtime would, shared by the tests.
+#![allow(dead_code)]
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::operation::{Operation, Outcome};
+use iced::advanced::widget::Tree;
+use iced::advanced::{clipboard, mouse, renderer, Clipboard, Shell};
+use iced::keyboard::key::{NativeCode, Physical};
+use iced::keyboard::{self, Key, Location, Modifiers};
+use iced::time::Instant;
+use iced::{window, Event, Font, Point, Rectangle, Size, Theme};
+
+pub type Renderer = iced_tiny_skia::Renderer;
+pub type Element<'a, Message> = iced::Element<'a, Message, Theme, Renderer>;
+
+/// A laid out widget tree, driven like a runtime would.
+pub struct Ui<'a, Message> {
+    pub root: Element<'a, Message>,
+    pub tree: Tree,
+    pub node: layout::Node,
+    pub renderer: Renderer,
+}
+
+impl<'a, Message> Ui<'a, Message> {
+    pub fn new(root: impl Into<Element<'a, Message>>) -> Self {
+        let root = root.into();
+        let renderer = Renderer::new(Font::DEFAULT, 16.into());
+        let mut tree = Tree::new(&root);
+        let node = root.as_widget().layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(800.0, 600.0)),
+        );
+
+        Self {
+            root,
+            tree,
+            node,
+            renderer,
+        }
+    }
+
+    pub fn operate(&mut self, operation: impl Operation<()> + 'static) {
+        let mut operation: Box<dyn Operation<()>> = Box::new(operation);
+
+        loop {
+            self.root.as_widget().operate(
+                &mut self.tree,
+                Layout::new(&self.node),
+                &self.renderer,
+                operation.as_mut(),
+            );
+
+            match operation.finish() {
+                Outcome::Chain(next) => operation = next,
+                Outcome::None | Outcome::Some(()) => break,
+            }
+        }
+    }
+
+    pub fn event(&mut self, event: Event) -> Vec<Message> {
+        self.event_with(event, &mut clipboard::Null)
+    }
+
+    /// Handles an event with the given clipboard.
+    pub fn event_with(
+        &mut self,
+        event: Event,
+        clipboard: &mut dyn Clipboard,
+    ) -> Vec<Message> {
+        self.handle(event, mouse::Cursor::Unavailable, clipboard)
+    }
+
+    /// Presses and releases the left mouse button at the given position.
+    pub fn click(&mut self, position: Point) -> Vec<Message> {
+        let cursor = mouse::Cursor::Available(position);
+
+        let mut messages = self.handle(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            cursor,
+            &mut clipboard::Null,
+        );
+        messages.extend(self.handle(
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            cursor,
+            &mut clipboard::Null,
+        ));
+
+        messages
+    }
+
+    fn handle(
+        &mut self,
+        event: Event,
+        cursor: mouse::Cursor,
+        clipboard: &mut dyn Clipboard,
+    ) -> Vec<Message> {
+        let mut messages = Vec::new();
+
+        let _ = self.root.as_widget_mut().on_event(
+            &mut self.tree,
+            event,
+            Layout::new(&self.node),
+            cursor,
+            &self.renderer,
+            clipboard,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(Size::new(800.0, 600.0)),
+        );
+
+        messages
+    }
+
+    pub fn draw(&mut self) {
+        self.root.as_widget().draw(
+            &self.tree,
+            &mut self.renderer,
+            &Theme::Light,
+            &renderer::Style::default(),
+            Layout::new(&self.node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(Size::new(800.0, 600.0)),
+        );
+    }
+
+    pub fn redraw(&mut self) -> Vec<Message> {
+        self.event(Event::Window(
+            window::Event::RedrawRequested(Instant::now()),
+        ))
+    }
+}
+
+/// A key press with the given modifiers, producing the given text.
+pub fn key_press(key: Key, modifiers: Modifiers, text: Option<&str>) -> Event {
+    Event::Keyboard(keyboard::Event::KeyPressed {
+        modified_key: key.clone(),
+        key,
+        physical_key: Physical::Unidentified(NativeCode::Unidentified),
+        location: Location::Standard,
+        modifiers,
+        text: text.map(Into::into),
+    })
+}
+
+/// The release of the given key.
+pub fn key_release(key: Key, modifiers: Modifiers) -> Event {
+    Event::Keyboard(keyboard::Event::KeyReleased {
+        key,
+        location: Location::Standard,
+        modifiers,
+    })
+}
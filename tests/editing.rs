@@ -0,0 +1,87 @@
+//! Checks how typing edits the value of a `TextInput`.
+mod common;
+
+use iced::advanced::clipboard::{self, Clipboard};
+use iced::advanced::widget::operation::focusable;
+use iced::keyboard::{self, key, Key, Modifiers};
+use iced::{Event, Theme};
+
+use sweeten::widget::text_input::{Id, TextInput};
+
+use common::{key_press, Renderer, Ui};
+
+fn input(value: &str) -> TextInput<'_, String, Theme, Renderer> {
+    TextInput::new("", value)
+        .id("input")
+        .on_input(|value| value)
+}
+
+fn type_text(ui: &mut Ui<'_, String>, text: &str) -> Vec<String> {
+    ui.event(key_press(
+        Key::Character(text.into()),
+        Modifiers::default(),
+        Some(text),
+    ))
+}
+
+#[test]
+fn combining_mark_keeps_the_cursor_after_it() {
+    let mut ui = Ui::new(input("ab"));
+    ui.operate(focusable::focus(Id::new("input").into()));
+
+    let _ = ui.event(key_press(
+        Key::Named(key::Named::ArrowLeft),
+        Modifiers::default(),
+        None,
+    ));
+
+    assert_eq!(type_text(&mut ui, "\u{301}"), vec!["a\u{301}b"]);
+    assert_eq!(type_text(&mut ui, "x"), vec!["a\u{301}xb"]);
+}
+
+/// A clipboard holding the given text.
+struct Holding(&'static str);
+
+impl Clipboard for Holding {
+    fn read(&self, _kind: clipboard::Kind) -> Option<String> {
+        Some(self.0.to_owned())
+    }
+
+    fn write(&mut self, _kind: clipboard::Kind, _contents: String) {}
+}
+
+#[test]
+fn pasted_combining_mark_joins_the_previous_grapheme() {
+    let mut ui = Ui::new(input("ab"));
+    ui.operate(focusable::focus(Id::new("input").into()));
+
+    let _ = ui.event(key_press(
+        Key::Named(key::Named::ArrowLeft),
+        Modifiers::default(),
+        None,
+    ));
+    let _ = ui.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+        Modifiers::COMMAND,
+    )));
+
+    assert_eq!(
+        ui.event_with(
+            key_press(Key::Character("v".into()), Modifiers::COMMAND, None),
+            &mut Holding("\u{301}"),
+        ),
+        vec!["a\u{301}b"]
+    );
+
+    let _ = ui.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+        Modifiers::default(),
+    )));
+
+    assert_eq!(
+        ui.event(key_press(
+            Key::Named(key::Named::Backspace),
+            Modifiers::default(),
+            None,
+        )),
+        vec!["b"]
+    );
+}
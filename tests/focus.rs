@@ -0,0 +1,258 @@
+//! Checks how focus moves between widgets and how they report it.
+mod common;
+
+use iced::advanced::widget::operation::{focusable, Focusable, Operation};
+use iced::advanced::widget::Id;
+use iced::keyboard::{key, Key, Modifiers};
+use iced::widget::column;
+use iced::{Point, Rectangle, Theme};
+
+use std::sync::{Arc, Mutex};
+
+use sweeten::widget::pick_list::PickList;
+use sweeten::widget::text_input::{self, FocusReason};
+use sweeten::widget::{focus_scope, operation};
+
+use common::{key_press, Renderer, Ui};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Focused(&'static str, FocusReason),
+    Input(String),
+    Picked(&'static str),
+    Cleared,
+    Blurred,
+}
+
+const LANGUAGES: &[&str] = &["Rust", "Go", "Zig"];
+
+fn input(
+    id: &'static str,
+) -> text_input::TextInput<'static, Message, Theme, Renderer> {
+    text_input::TextInput::new("", "")
+        .id(id)
+        .on_input(Message::Input)
+        .on_focus(move |_, reason| Message::Focused(id, reason))
+}
+
+#[test]
+fn focus_next_reports_keyboard_focus() {
+    let mut ui = Ui::new(column![input("first"), input("second")]);
+
+    ui.operate(operation::focus_next(None));
+    assert_eq!(
+        ui.redraw(),
+        vec![Message::Focused("first", FocusReason::Keyboard)]
+    );
+
+    ui.operate(operation::focus_next(None));
+    assert_eq!(
+        ui.redraw(),
+        vec![Message::Focused("second", FocusReason::Keyboard)]
+    );
+}
+
+#[test]
+fn focus_next_normalizes_the_input_it_leaves() {
+    let mut ui = Ui::new(column![
+        text_input::TextInput::new("", " rust ")
+            .id("first")
+            .on_input(Message::Input)
+            .on_blur(Message::Blurred)
+            .normalize(text_input::Normalization::Trim),
+        input("second"),
+    ]);
+
+    ui.operate(operation::focus_next(None));
+    let _ = ui.redraw();
+
+    ui.operate(operation::focus_next(None));
+    assert_eq!(
+        ui.redraw(),
+        vec![
+            Message::Input("rust".to_owned()),
+            Message::Blurred,
+            Message::Focused("second", FocusReason::Keyboard),
+        ]
+    );
+}
+
+#[test]
+fn focus_by_id_reports_programmatic_focus() {
+    let mut ui = Ui::new(column![input("first"), input("second")]);
+
+    ui.operate(focusable::focus(text_input::Id::new("second").into()));
+    assert_eq!(
+        ui.redraw(),
+        vec![Message::Focused("second", FocusReason::Programmatic)]
+    );
+}
+
+fn pick_list() -> PickList<
+    'static,
+    &'static str,
+    &'static [&'static str],
+    &'static str,
+    Message,
+    Theme,
+    Renderer,
+> {
+    PickList::new(LANGUAGES, Some("Rust"), Message::Picked)
+        .on_clear(Message::Cleared)
+}
+
+/// Types `text` and then presses Backspace, returning what is produced.
+fn type_and_erase(ui: &mut Ui<'_, Message>, text: &str) -> Vec<Message> {
+    let mut messages = ui.event(key_press(
+        Key::Character(text.into()),
+        Modifiers::default(),
+        Some(text),
+    ));
+
+    messages.extend(ui.event(key_press(
+        Key::Named(key::Named::Backspace),
+        Modifiers::default(),
+        None,
+    )));
+
+    messages
+}
+
+/// Collects the [`Id`] of every focused widget.
+#[derive(Default)]
+struct FindAllFocused(Arc<Mutex<Vec<Id>>>);
+
+impl Operation for FindAllFocused {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        if let Some(id) = id.filter(|_| state.is_focused()) {
+            self.0.lock().unwrap().push(id.clone());
+        }
+    }
+}
+
+fn find_all_focused(ui: &mut Ui<'_, Message>) -> Vec<Id> {
+    let operation = FindAllFocused::default();
+    let focused = Arc::clone(&operation.0);
+
+    ui.operate(operation);
+
+    let focused = focused.lock().unwrap().clone();
+    focused
+}
+
+#[test]
+fn negative_tab_index_moves_to_its_neighbors() {
+    let mut ui = Ui::new(column![
+        input("first"),
+        input("skipped").tab_index(-1),
+        input("last"),
+    ]);
+
+    ui.operate(focusable::focus(text_input::Id::new("skipped").into()));
+    let _ = ui.redraw();
+
+    ui.operate(operation::focus_next(None));
+    assert_eq!(
+        ui.redraw(),
+        vec![Message::Focused("last", FocusReason::Keyboard)]
+    );
+
+    ui.operate(focusable::focus(text_input::Id::new("skipped").into()));
+    let _ = ui.redraw();
+
+    ui.operate(operation::focus_previous(None));
+    assert_eq!(
+        ui.redraw(),
+        vec![Message::Focused("first", FocusReason::Keyboard)]
+    );
+}
+
+#[test]
+fn focus_scope_takes_the_focus_from_the_rest_of_the_tree() {
+    let mut ui = Ui::new(column![
+        input("outside"),
+        focus_scope::FocusScope::new(
+            "dialog",
+            column![input("first"), input("second")]
+        ),
+    ]);
+
+    ui.operate(focusable::focus(text_input::Id::new("outside").into()));
+    let _ = ui.redraw();
+
+    ui.operate(operation::focus_next(Some(
+        focus_scope::Id::new("dialog").into(),
+    )));
+    assert_eq!(
+        ui.redraw(),
+        vec![Message::Focused("first", FocusReason::Keyboard)]
+    );
+
+    assert_eq!(find_all_focused(&mut ui), vec![Id::new("first")]);
+}
+
+#[test]
+fn focused_pick_list_takes_type_ahead_and_clear() {
+    let mut ui = Ui::new(column![pick_list(), input("name")]);
+
+    ui.operate(operation::focus_next(None));
+
+    assert_eq!(
+        type_and_erase(&mut ui, "z"),
+        vec![Message::Picked("Zig"), Message::Cleared]
+    );
+}
+
+#[test]
+fn open_pick_list_leaves_backspace_to_the_search() {
+    let mut ui = Ui::new(column![pick_list(), input("name")]);
+
+    ui.operate(operation::focus_next(None));
+    let _ = ui.click(Point::new(5.0, 5.0));
+
+    assert_eq!(
+        ui.event(key_press(
+            Key::Named(key::Named::Backspace),
+            Modifiers::default(),
+            None,
+        )),
+        vec![]
+    );
+}
+
+#[test]
+fn focusing_another_widget_blurs_pick_list() {
+    let mut ui = Ui::new(column![pick_list(), input("name")]);
+
+    ui.operate(operation::focus_next(None));
+    ui.operate(operation::focus_next(None));
+    let _ = ui.redraw();
+
+    assert_eq!(
+        type_and_erase(&mut ui, "z"),
+        vec![Message::Input("z".into()), Message::Input(String::new())]
+    );
+}
+
+#[test]
+fn escape_blurs_pick_list() {
+    let mut ui = Ui::new(column![pick_list(), input("name")]);
+
+    ui.operate(operation::focus_next(None));
+    let _ = ui.event(key_press(
+        Key::Named(key::Named::Escape),
+        Modifiers::default(),
+        None,
+    ));
+
+    assert_eq!(type_and_erase(&mut ui, "z"), vec![]);
+}
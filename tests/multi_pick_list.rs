@@ -0,0 +1,79 @@
+//! Checks how a `MultiPickList` opens, closes and takes typed text.
+mod common;
+
+use iced::keyboard::{key, Key, Modifiers};
+use iced::{Point, Theme};
+
+use sweeten::widget::multi_pick_list::MultiPickList;
+
+use common::{key_press, Renderer, Ui};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Toggled(&'static str, bool),
+    Opened,
+    Closed,
+}
+
+const LANGUAGES: &[&str] = &["Rust", "Go", "Zig", "Zsh"];
+
+fn multi_pick_list() -> MultiPickList<
+    'static,
+    &'static str,
+    &'static [&'static str],
+    &'static [&'static str],
+    Message,
+    Theme,
+    Renderer,
+> {
+    MultiPickList::new(LANGUAGES, &LANGUAGES[..1])
+        .on_toggle(Message::Toggled)
+        .on_open(Message::Opened)
+        .on_close(Message::Closed)
+}
+
+fn press(
+    ui: &mut Ui<'_, Message>,
+    key: Key,
+    text: Option<&str>,
+) -> Vec<Message> {
+    ui.event(key_press(key, Modifiers::default(), text))
+}
+
+#[test]
+fn escape_and_click_outside_produce_on_close() {
+    let mut ui = Ui::new(multi_pick_list());
+
+    assert_eq!(ui.click(Point::new(5.0, 5.0)), vec![Message::Opened]);
+    assert_eq!(
+        press(&mut ui, Key::Named(key::Named::Escape), None),
+        vec![Message::Closed]
+    );
+
+    assert_eq!(ui.click(Point::new(5.0, 5.0)), vec![Message::Opened]);
+    assert_eq!(ui.click(Point::new(500.0, 500.0)), vec![Message::Closed]);
+}
+
+#[test]
+fn typing_highlights_the_matching_option() {
+    let mut ui = Ui::new(multi_pick_list());
+    let _ = ui.click(Point::new(5.0, 5.0));
+
+    assert_eq!(
+        press(&mut ui, Key::Character("z".into()), Some("z")),
+        vec![]
+    );
+    assert_eq!(
+        press(&mut ui, Key::Named(key::Named::Enter), None),
+        vec![Message::Toggled("Zig", true)]
+    );
+
+    assert_eq!(
+        press(&mut ui, Key::Character("z".into()), Some("z")),
+        vec![]
+    );
+    assert_eq!(
+        press(&mut ui, Key::Named(key::Named::Enter), None),
+        vec![Message::Toggled("Zsh", true)]
+    );
+}
@@ -0,0 +1,181 @@
+//! Checks that the graphemes of a secure `Value`, and of a secure
+//! `TextInput`, are wiped before their memory is given back to the
+//! allocator.
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use iced::advanced::clipboard::{self, Clipboard};
+use iced::advanced::widget::operation::focusable;
+use iced::keyboard::{self, key, Key, Modifiers};
+use iced::{Event, Theme};
+use zeroize::Zeroizing;
+
+use sweeten::widget::text_input::{Id, TextInput, Value};
+
+use common::{key_press, Renderer, Ui};
+
+/// A multi-byte grapheme that nothing else in the test allocates.
+const SECRET: &str = "🔑";
+
+/// Counts freed allocations that still contain the secret.
+struct Scanner;
+
+static LEAKS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Scanner {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // Zeroed, so every byte scanned on `dealloc` is initialized.
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let bytes = std::slice::from_raw_parts(ptr, layout.size());
+
+        if bytes
+            .windows(SECRET.len())
+            .any(|window| window == SECRET.as_bytes())
+        {
+            let _ = LEAKS.fetch_add(1, Ordering::SeqCst);
+        }
+
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Scanner = Scanner;
+
+/// Keeps the tests from counting each other's leaks.
+static SCAN: Mutex<()> = Mutex::new(());
+
+/// Counts the allocations freed with the secret still in them while `f`
+/// runs.
+fn leaks_of(f: impl FnOnce()) -> usize {
+    let _scan = SCAN.lock().unwrap_or_else(PoisonError::into_inner);
+    let leaks = LEAKS.load(Ordering::SeqCst);
+
+    f();
+
+    LEAKS.load(Ordering::SeqCst) - leaks
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Input(Zeroizing<String>),
+}
+
+fn input(is_secure: bool) -> TextInput<'static, Message, Theme, Renderer> {
+    TextInput::new("", "🔑🔑🔑🔑")
+        .id("secret")
+        .secure(is_secure)
+        .on_input(|value| Message::Input(Zeroizing::new(value)))
+}
+
+/// A clipboard that records what is written to it.
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+impl Clipboard for Recorder {
+    fn read(&self, _kind: clipboard::Kind) -> Option<String> {
+        None
+    }
+
+    fn write(&mut self, _kind: clipboard::Kind, contents: String) {
+        self.0.push(contents);
+    }
+}
+
+#[test]
+fn secure_value_is_wiped_on_drop() {
+    let leaks = leaks_of(|| {
+        let mut value = Value::new_secure("🔑🔑🔑🔑");
+
+        let _ = value.insert(2, '🔑');
+        value.remove(0);
+        value.remove_many(0, 2);
+        let _ = value.insert_many(1, Value::new("🔑🔑"));
+
+        let selection = value.select(1, 3);
+        let prefix = value.until(2);
+        let copy = value.clone();
+
+        assert_eq!(value.len(), 4);
+        assert_eq!(selection.len(), 2);
+        assert_eq!(prefix.len(), 2);
+        assert_eq!(copy.len(), 4);
+        assert_eq!(value.secure().len(), 4);
+    });
+
+    assert_eq!(leaks, 0);
+}
+
+#[test]
+fn secure_input_keeps_its_value_out_of_the_text_cache() {
+    let layout_and_draw = |is_secure| {
+        leaks_of(|| {
+            let mut ui = Ui::new(input(is_secure));
+            ui.draw();
+        })
+    };
+
+    // A plain input shapes its value, so copies of it are freed as is.
+    assert!(layout_and_draw(false) > 0);
+    assert_eq!(layout_and_draw(true), 0);
+}
+
+#[test]
+fn secure_input_is_wiped_on_drop() {
+    let leaks = leaks_of(|| {
+        let mut ui = Ui::new(input(true));
+        ui.operate(focusable::focus(Id::new("secret").into()));
+
+        let mut messages = ui.event(key_press(
+            Key::Character("🔑".into()),
+            Modifiers::default(),
+            Some("🔑"),
+        ));
+        messages.extend(ui.event(key_press(
+            Key::Named(key::Named::Backspace),
+            Modifiers::default(),
+            None,
+        )));
+        ui.draw();
+
+        assert!(matches!(
+            &messages[..],
+            [Message::Input(typed), Message::Input(erased)]
+                if typed.as_str() == "🔑🔑🔑🔑🔑" && erased.as_str() == "🔑🔑🔑🔑"
+        ));
+    });
+
+    assert_eq!(leaks, 0);
+}
+
+#[test]
+fn secure_input_refuses_copy_and_cut() {
+    let leaks = leaks_of(|| {
+        let mut ui = Ui::new(input(true));
+        let mut clipboard = Recorder::default();
+        ui.operate(focusable::focus(Id::new("secret").into()));
+
+        let _ = ui.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+            Modifiers::COMMAND,
+        )));
+
+        for key in ["a", "c", "x"] {
+            let messages = ui.event_with(
+                key_press(Key::Character(key.into()), Modifiers::COMMAND, None),
+                &mut clipboard,
+            );
+
+            assert!(messages.is_empty());
+        }
+
+        assert!(clipboard.0.is_empty());
+    });
+
+    assert_eq!(leaks, 0);
+}
@@ -0,0 +1,82 @@
+//! Checks how a `TagInput` turns pasted text into tags.
+mod common;
+
+use iced::advanced::clipboard::{self, Clipboard};
+use iced::advanced::widget::operation::focusable;
+use iced::keyboard::{self, key, Key, Modifiers};
+use iced::{Event, Theme};
+
+use sweeten::widget::tag_input::TagInput;
+use sweeten::widget::text_input::Id;
+
+use common::{key_press, Renderer, Ui};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Draft(String),
+    Added(String),
+}
+
+fn tag_input(draft: &str) -> TagInput<'_, Message, Theme, Renderer> {
+    TagInput::new("", draft, Vec::<String>::new())
+        .id("tags")
+        .on_input(Message::Draft)
+        .on_add(Message::Added)
+}
+
+/// A clipboard holding the given text.
+struct Holding(&'static str);
+
+impl Clipboard for Holding {
+    fn read(&self, _kind: clipboard::Kind) -> Option<String> {
+        Some(self.0.to_owned())
+    }
+
+    fn write(&mut self, _kind: clipboard::Kind, _contents: String) {}
+}
+
+fn paste(ui: &mut Ui<'_, Message>, text: &'static str) -> Vec<Message> {
+    let _ = ui.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+        Modifiers::COMMAND,
+    )));
+
+    ui.event_with(
+        key_press(Key::Character("v".into()), Modifiers::COMMAND, None),
+        &mut Holding(text),
+    )
+}
+
+#[test]
+fn pasted_separators_finish_the_draft_and_start_a_new_one() {
+    let mut ui = Ui::new(tag_input("foo"));
+    ui.operate(focusable::focus(Id::new("tags").into()));
+
+    assert_eq!(
+        paste(&mut ui, "bar,baz\nqux"),
+        vec![
+            Message::Added("foobar".to_owned()),
+            Message::Added("baz".to_owned()),
+            Message::Draft("qux".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn text_after_the_cursor_stays_in_the_draft() {
+    let mut ui = Ui::new(tag_input("ab"));
+    ui.operate(focusable::focus(Id::new("tags").into()));
+
+    let _ = ui.event(key_press(
+        Key::Named(key::Named::ArrowLeft),
+        Modifiers::default(),
+        None,
+    ));
+
+    assert_eq!(
+        paste(&mut ui, "x,y"),
+        vec![
+            Message::Added("ax".to_owned()),
+            Message::Draft("yb".to_owned()),
+        ]
+    );
+}